@@ -0,0 +1,44 @@
+//! Compares the lookup tables of the `lut` feature with the weighted sum of `Rgb<u8>`.
+//!
+//! Run with `cargo bench --features lut`.
+use relative_luminance::{lut, Luminance, Rgb};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// The number of times every pixel is measured.
+const ROUNDS: usize = 50;
+
+/// Gets the fastest time of a round over the pixels.
+fn fastest(pixels: &[[u8; 3]], luminance: impl Fn(u8, u8, u8) -> f32) -> Duration {
+    (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            let sum: f32 = pixels
+                .iter()
+                .map(|&[r, g, b]| luminance(black_box(r), black_box(g), black_box(b)))
+                .sum();
+            black_box(sum);
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+fn main() {
+    // About a million scrambled pixels, so the lookups don't follow a pattern
+    let pixels: Vec<[u8; 3]> = (0..1 << 20)
+        .map(|i: u32| {
+            let [a, b, c, _] = i.wrapping_mul(2_654_435_761).to_le_bytes();
+            [a, b, c]
+        })
+        .collect();
+
+    let weighted = fastest(&pixels, |r, g, b| {
+        Rgb::<u8>::new(r, g, b).relative_luminance()
+    });
+    let table = fastest(&pixels, lut::relative_luminance);
+    let per_pixel = |time: Duration| time.as_secs_f64() * 1e9 / pixels.len() as f64;
+
+    println!("Rgb<u8>: {:.3} ns/pixel", per_pixel(weighted));
+    println!("lut:     {:.3} ns/pixel", per_pixel(table));
+}
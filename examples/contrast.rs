@@ -1,18 +1,5 @@
-use hsl::HSL;
 use owo_colors::{OwoColorize, Rgb};
-use relative_luminance::{Luminance, Rgb as LuminanceRgb};
-
-struct RgbWrapper(Rgb);
-
-impl Luminance<f32> for RgbWrapper {
-    fn luminance_rgb(&self) -> LuminanceRgb<f32> {
-        LuminanceRgb {
-            r: f32::from(self.0 .0) / 255.0,
-            g: f32::from(self.0 .1) / 255.0,
-            b: f32::from(self.0 .2) / 255.0,
-        }
-    }
-}
+use relative_luminance::{contrast, Hsl, Luminance};
 
 fn main() {
     println!("In this example we use black text at >0.5 brightness and white text at <=0.5");
@@ -30,7 +17,7 @@ fn main() {
     println!("Using the lightness from HSL:");
     colors.iter().for_each(|(label, bg)| {
         let bg = *bg;
-        let hsl = HSL::from_rgb(&[bg.0, bg.1, bg.2]);
+        let hsl = Hsl::from_rgb(&bg.luminance_rgb());
         let fg = if hsl.l > 0.5 {
             Rgb(0, 0, 0)
         } else {
@@ -47,7 +34,7 @@ fn main() {
     println!("Using relative luminance:");
     colors.iter().for_each(|(label, bg)| {
         let bg = *bg;
-        let luminance = RgbWrapper(bg).relative_luminance();
+        let luminance = bg.relative_luminance();
         let fg = if luminance > 0.5 {
             Rgb(0, 0, 0)
         } else {
@@ -60,4 +47,14 @@ fn main() {
             luminance
         );
     });
+    println!("Using the higher contrast ratio:");
+    colors.iter().for_each(|(label, bg)| {
+        let fg = *contrast::text_color_with(bg, &Rgb(0, 0, 0), &Rgb(255, 255, 255));
+
+        println!(
+            "{: ^10} ({:.2} contrast ratio)",
+            label.color(fg).on_color(*bg),
+            bg.contrast_ratio(&fg)
+        );
+    });
 }
@@ -0,0 +1,109 @@
+//! The colors of terminal palettes.
+//!
+//! Terminals can change their palettes, so these are the defaults of xterm, which
+//! many terminals use or approximate. Indices 0 to 15 are the 16 standard colors,
+//! 16 to 231 are a 6×6×6 color cube, and 232 to 255 are a ramp of grays.
+//!
+//! ```
+//! use relative_luminance::ansi;
+//!
+//! let blue = ansi::indexed(4);
+//! let orange = ansi::indexed(208);
+//!
+//! assert_eq!((blue.r, blue.g, blue.b), (0, 0, 238));
+//! assert_eq!((orange.r, orange.g, orange.b), (255, 135, 0));
+//! ```
+use crate::Rgb;
+
+/// The 16 standard colors, from black to bright white.
+const STANDARD: [[u8; 3]; 16] = [
+    [0, 0, 0],
+    [205, 0, 0],
+    [0, 205, 0],
+    [205, 205, 0],
+    [0, 0, 238],
+    [205, 0, 205],
+    [0, 205, 205],
+    [229, 229, 229],
+    [127, 127, 127],
+    [255, 0, 0],
+    [0, 255, 0],
+    [255, 255, 0],
+    [92, 92, 255],
+    [255, 0, 255],
+    [0, 255, 255],
+    [255, 255, 255],
+];
+
+/// The levels of each channel in the color cube.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Gets the color of an index in the default 256-color palette.
+pub const fn indexed(index: u8) -> Rgb<u8> {
+    let [r, g, b] = match index {
+        0..=15 => STANDARD[index as usize],
+        16..=231 => {
+            let cube = (index - 16) as usize;
+            [
+                CUBE_LEVELS[cube / 36],
+                CUBE_LEVELS[cube / 6 % 6],
+                CUBE_LEVELS[cube % 6],
+            ]
+        }
+        232..=255 => {
+            let gray = 8 + 10 * (index - 232);
+            [gray, gray, gray]
+        }
+    };
+    Rgb { r, g, b }
+}
+
+/// The relative luminance of every index in the default 256-color palette.
+///
+/// This gives the same results as the [`Rgb<u8>`] colors of [`indexed`].
+pub static LUMINANCES: [f32; 256] = luminance_table!(|index| {
+    let Rgb { r, g, b } = indexed(index as u8);
+    [r, g, b]
+});
+
+/// Gets the relative luminance of an index in the default 256-color palette, from
+/// [`LUMINANCES`].
+///
+/// ```
+/// use relative_luminance::ansi_index_luminance;
+///
+/// assert_eq!(ansi_index_luminance(15), 1.0);
+/// assert!(ansi_index_luminance(4) < 0.1);
+/// ```
+#[inline]
+pub fn ansi_index_luminance(index: u8) -> f32 {
+    LUMINANCES[usize::from(index)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ranges() {
+        let rgb = |index| {
+            let Rgb { r, g, b } = indexed(index);
+            (r, g, b)
+        };
+        assert_eq!(rgb(15), (255, 255, 255));
+        assert_eq!(rgb(16), (0, 0, 0));
+        assert_eq!(rgb(231), (255, 255, 255));
+        assert_eq!(rgb(232), (8, 8, 8));
+        assert_eq!(rgb(255), (238, 238, 238));
+    }
+
+    #[test]
+    fn test_luminances_match_rgb() {
+        for index in 0..=255 {
+            assert_eq!(
+                ansi_index_luminance(index),
+                indexed(index).relative_luminance()
+            );
+        }
+    }
+}
@@ -0,0 +1,484 @@
+//! The [Advanced Perceptual Contrast Algorithm][apca] (APCA).
+//!
+//! APCA produces a lightness contrast value (Lc) for a text color on a background
+//! color. Unlike the WCAG 2 contrast ratio, APCA is polarity-sensitive: dark text on
+//! a light background produces a positive Lc, and light text on a dark background
+//! produces a negative Lc. Lc values range from roughly -108 to 106.
+//!
+//! This module uses the constants from APCA version 0.0.98G.
+//!
+//! ```
+//! use relative_luminance::{apca, Rgb};
+//!
+//! let black: Rgb<f64> = Rgb::new(0.0, 0.0, 0.0);
+//! let white: Rgb<f64> = Rgb::new(1.0, 1.0, 1.0);
+//!
+//! assert!((apca::contrast(&black, &white) - 106.04).abs() < 0.01);
+//! assert!((apca::contrast(&white, &black) + 107.88).abs() < 0.01);
+//! ```
+//!
+//! [apca]: https://github.com/Myndex/SAPC-APCA
+use crate::{Float, Luminance, LuminanceValue, Rgb};
+
+const MAIN_TRC: f64 = 2.4;
+const RED_COEFFICIENT: f64 = 0.2126729;
+const GREEN_COEFFICIENT: f64 = 0.7151522;
+const BLUE_COEFFICIENT: f64 = 0.0721750;
+
+const NORMAL_BACKGROUND: f64 = 0.56;
+const NORMAL_TEXT: f64 = 0.57;
+const REVERSE_TEXT: f64 = 0.62;
+const REVERSE_BACKGROUND: f64 = 0.65;
+
+const BLACK_THRESHOLD: f64 = 0.022;
+const BLACK_CLAMP: f64 = 1.414;
+const SCALE: f64 = 1.14;
+const LOW_OFFSET: f64 = 0.027;
+const LOW_CLIP: f64 = 0.1;
+const DELTA_Y_MIN: f64 = 0.0005;
+
+/// Gets the estimated screen luminance (Ys) that APCA uses as its input.
+///
+/// The channels returned by [`Luminance::luminance_rgb`] are treated as sRGB in the
+/// range [0.0, 1.0]. Note that APCA intentionally uses a simple 2.4 exponent instead of
+/// the piecewise sRGB curve, so this is *not* the same as
+/// [`Luminance::wcag_relative_luminance`].
+///
+/// ```
+/// use relative_luminance::{apca, Rgb};
+///
+/// let white: Rgb<f32> = Rgb::new(1.0, 1.0, 1.0);
+///
+/// assert!((apca::screen_luminance(&white) - 1.0).abs() < 0.0001);
+/// ```
+pub fn screen_luminance<T, C>(color: &C) -> T::Channel
+where
+    T: LuminanceValue,
+    T::Channel: Float,
+    C: Luminance<T> + ?Sized,
+{
+    let Rgb { r, g, b } = color.luminance_rgb();
+    let trc = T::Channel::from_f64(MAIN_TRC);
+    r.powf(trc) * T::Channel::from_f64(RED_COEFFICIENT)
+        + g.powf(trc) * T::Channel::from_f64(GREEN_COEFFICIENT)
+        + b.powf(trc) * T::Channel::from_f64(BLUE_COEFFICIENT)
+}
+
+/// Gets the APCA lightness contrast (Lc) of a text color on a background color.
+///
+/// ```
+/// use relative_luminance::{apca, Rgb};
+///
+/// let gray: Rgb<f64> = Rgb::new(136.0 / 255.0, 136.0 / 255.0, 136.0 / 255.0);
+/// let white: Rgb<f64> = Rgb::new(1.0, 1.0, 1.0);
+///
+/// // Dark text on a light background is positive...
+/// assert!(apca::contrast(&gray, &white) > 0.0);
+/// // ...and light text on a dark background is negative.
+/// assert!(apca::contrast(&white, &gray) < 0.0);
+/// ```
+pub fn contrast<T, Text, Background>(text: &Text, background: &Background) -> T::Channel
+where
+    T: LuminanceValue,
+    T::Channel: Float,
+    Text: Luminance<T> + ?Sized,
+    Background: Luminance<T> + ?Sized,
+{
+    contrast_from_luminance(screen_luminance(text), screen_luminance(background))
+}
+
+/// Gets the APCA lightness contrast (Lc) from screen luminances, like the ones returned
+/// by [`screen_luminance`].
+///
+/// ```
+/// use relative_luminance::apca;
+///
+/// assert_eq!(apca::contrast_from_luminance(0.5f32, 0.5), 0.0);
+/// ```
+pub fn contrast_from_luminance<F: Float>(text: F, background: F) -> F {
+    let f = F::from_f64;
+    let text = soft_clamp_black(text);
+    let background = soft_clamp_black(background);
+
+    if (background - text).abs() < f(DELTA_Y_MIN) {
+        return f(0.0);
+    }
+
+    let contrast = if background > text {
+        // Dark text on a light background
+        let background = background.powf(f(NORMAL_BACKGROUND));
+        let sapc = (background - text.powf(f(NORMAL_TEXT))) * f(SCALE);
+        if sapc < f(LOW_CLIP) {
+            f(0.0)
+        } else {
+            sapc - f(LOW_OFFSET)
+        }
+    } else {
+        // Light text on a dark background
+        let background = background.powf(f(REVERSE_BACKGROUND));
+        let sapc = (background - text.powf(f(REVERSE_TEXT))) * f(SCALE);
+        if sapc > f(0.0) - f(LOW_CLIP) {
+            f(0.0)
+        } else {
+            sapc + f(LOW_OFFSET)
+        }
+    };
+
+    contrast * f(100.0)
+}
+
+/// The minimum font sizes, in CSS pixels, for each Lc and font weight from 100 to 900,
+/// from the APCA 0.1.9 "G-4g" lookup table.
+///
+/// [`None`] is a contrast that's too low for any text.
+const FONT_SIZES: [(f64, [Option<f64>; 9]); 22] = [
+    (0.0, [None, None, None, None, None, None, None, None, None]),
+    (10.0, [None, None, None, None, None, None, None, None, None]),
+    (15.0, [None, None, None, None, None, None, None, None, None]),
+    (20.0, [None, None, None, None, None, None, None, None, None]),
+    (
+        25.0,
+        [
+            None,
+            None,
+            None,
+            Some(120.0),
+            Some(120.0),
+            Some(108.0),
+            Some(96.0),
+            Some(96.0),
+            Some(96.0),
+        ],
+    ),
+    (
+        30.0,
+        [
+            None,
+            None,
+            Some(120.0),
+            Some(108.0),
+            Some(108.0),
+            Some(96.0),
+            Some(72.0),
+            Some(72.0),
+            Some(72.0),
+        ],
+    ),
+    (
+        35.0,
+        [
+            None,
+            Some(120.0),
+            Some(108.0),
+            Some(96.0),
+            Some(72.0),
+            Some(60.0),
+            Some(48.0),
+            Some(48.0),
+            Some(48.0),
+        ],
+    ),
+    (
+        40.0,
+        [
+            Some(120.0),
+            Some(108.0),
+            Some(96.0),
+            Some(60.0),
+            Some(48.0),
+            Some(42.0),
+            Some(32.0),
+            Some(32.0),
+            Some(32.0),
+        ],
+    ),
+    (
+        45.0,
+        [
+            Some(108.0),
+            Some(96.0),
+            Some(72.0),
+            Some(42.0),
+            Some(32.0),
+            Some(28.0),
+            Some(24.0),
+            Some(24.0),
+            Some(24.0),
+        ],
+    ),
+    (
+        50.0,
+        [
+            Some(96.0),
+            Some(72.0),
+            Some(60.0),
+            Some(32.0),
+            Some(28.0),
+            Some(24.0),
+            Some(21.0),
+            Some(21.0),
+            Some(21.0),
+        ],
+    ),
+    (
+        55.0,
+        [
+            Some(80.0),
+            Some(60.0),
+            Some(48.0),
+            Some(28.0),
+            Some(24.0),
+            Some(21.0),
+            Some(18.0),
+            Some(18.0),
+            Some(18.0),
+        ],
+    ),
+    (
+        60.0,
+        [
+            Some(72.0),
+            Some(48.0),
+            Some(42.0),
+            Some(24.0),
+            Some(21.0),
+            Some(18.0),
+            Some(16.0),
+            Some(16.0),
+            Some(18.0),
+        ],
+    ),
+    (
+        65.0,
+        [
+            Some(68.0),
+            Some(46.0),
+            Some(32.0),
+            Some(21.75),
+            Some(19.0),
+            Some(17.0),
+            Some(15.0),
+            Some(16.0),
+            Some(18.0),
+        ],
+    ),
+    (
+        70.0,
+        [
+            Some(64.0),
+            Some(44.0),
+            Some(28.0),
+            Some(19.5),
+            Some(18.0),
+            Some(16.0),
+            Some(14.5),
+            Some(16.0),
+            Some(18.0),
+        ],
+    ),
+    (
+        75.0,
+        [
+            Some(60.0),
+            Some(42.0),
+            Some(24.0),
+            Some(18.0),
+            Some(16.0),
+            Some(15.0),
+            Some(14.0),
+            Some(16.0),
+            Some(18.0),
+        ],
+    ),
+    (
+        80.0,
+        [
+            Some(56.0),
+            Some(38.25),
+            Some(23.0),
+            Some(17.25),
+            Some(15.81),
+            Some(14.81),
+            Some(14.0),
+            Some(16.0),
+            Some(18.0),
+        ],
+    ),
+    (
+        85.0,
+        [
+            Some(52.0),
+            Some(34.5),
+            Some(22.0),
+            Some(16.5),
+            Some(15.625),
+            Some(14.625),
+            Some(14.0),
+            Some(16.0),
+            Some(18.0),
+        ],
+    ),
+    (
+        90.0,
+        [
+            Some(48.0),
+            Some(32.0),
+            Some(21.0),
+            Some(16.0),
+            Some(15.5),
+            Some(14.5),
+            Some(14.0),
+            Some(16.0),
+            Some(18.0),
+        ],
+    ),
+    (
+        95.0,
+        [
+            Some(45.0),
+            Some(28.0),
+            Some(19.5),
+            Some(15.5),
+            Some(15.0),
+            Some(14.0),
+            Some(13.5),
+            Some(16.0),
+            Some(18.0),
+        ],
+    ),
+    (
+        100.0,
+        [
+            Some(42.0),
+            Some(26.5),
+            Some(18.5),
+            Some(15.0),
+            Some(14.5),
+            Some(13.5),
+            Some(13.0),
+            Some(16.0),
+            Some(18.0),
+        ],
+    ),
+    (
+        105.0,
+        [
+            Some(39.0),
+            Some(25.0),
+            Some(18.0),
+            Some(14.0),
+            Some(14.0),
+            Some(13.0),
+            Some(12.0),
+            Some(16.0),
+            Some(18.0),
+        ],
+    ),
+    (
+        110.0,
+        [
+            Some(36.0),
+            Some(24.0),
+            Some(18.0),
+            Some(14.0),
+            Some(13.0),
+            Some(12.0),
+            Some(11.0),
+            Some(16.0),
+            Some(18.0),
+        ],
+    ),
+];
+
+/// Gets the minimum font size, in CSS pixels, of text with a lightness contrast (Lc) and
+/// a font weight, or [`None`] if the contrast is too low for text of any size.
+///
+/// This uses the APCA lookup table for body text, interpolated between its Lc values.
+/// Either polarity of Lc can be given. Weights are clamped to [100, 900], and weights
+/// between the hundreds are rounded down, because a lighter weight needs a larger size.
+///
+/// ```
+/// use relative_luminance::{apca, Rgb};
+///
+/// let gray: Rgb<f64> = Rgb::new(0.55, 0.55, 0.55);
+/// let white: Rgb<f64> = Rgb::new(1.0, 1.0, 1.0);
+/// let lc = apca::contrast(&gray, &white);
+///
+/// assert_eq!(apca::minimum_font_size(75.0, 400), Some(18.0));
+/// assert!(apca::minimum_font_size(lc, 400).unwrap() > 18.0);
+/// assert_eq!(apca::minimum_font_size(-20.0, 900), None);
+/// ```
+pub fn minimum_font_size<F: Float>(lc: F, weight: u16) -> Option<F> {
+    let f = F::from_f64;
+    let lc = lc.abs();
+    let column = usize::from(weight.clamp(100, 900) / 100 - 1);
+    let below = FONT_SIZES
+        .iter()
+        .rposition(|&(row_lc, _)| f(row_lc) <= lc)
+        .unwrap_or(0);
+    let (below_lc, below_sizes) = FONT_SIZES[below];
+    let below_size = f(below_sizes[column]?);
+    match FONT_SIZES.get(below + 1) {
+        Some(&(above_lc, above_sizes)) => {
+            let Some(above_size) = above_sizes[column] else {
+                return Some(below_size);
+            };
+            let t = (lc - f(below_lc)) / f(above_lc - below_lc);
+            Some(below_size + (f(above_size) - below_size) * t)
+        }
+        // Contrasts above the table use its last row.
+        None => Some(below_size),
+    }
+}
+
+/// Softly clamps near-black luminances, compensating for flare and ambient light.
+fn soft_clamp_black<F: Float>(y: F) -> F {
+    let threshold = F::from_f64(BLACK_THRESHOLD);
+    if y > threshold {
+        y
+    } else {
+        y + (threshold - y).powf(F::from_f64(BLACK_CLAMP))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gray(value: u8) -> Rgb<f64> {
+        let value = f64::from(value) / 255.0;
+        Rgb::new(value, value, value)
+    }
+
+    #[test]
+    fn test_reference_values() {
+        // Reference values from the APCA 0.0.98G calculator
+        let cases = [
+            (gray(0x88), gray(0xFF), 63.06),
+            (gray(0xFF), gray(0x88), -68.54),
+            (gray(0x00), gray(0xAA), 58.15),
+            (gray(0xAA), gray(0x00), -56.24),
+        ];
+        for (text, background, expected) in cases {
+            let lc = contrast(&text, &background);
+            assert!((lc - expected).abs() < 0.01, "{lc} != {expected}");
+        }
+    }
+
+    #[test]
+    fn test_font_size_interpolation() {
+        assert_eq!(minimum_font_size(60.0f64, 400), Some(24.0));
+        assert_eq!(minimum_font_size(62.5f64, 400), Some(22.875));
+        assert_eq!(
+            minimum_font_size(62.5f64, 450),
+            minimum_font_size(62.5, 400)
+        );
+        assert_eq!(minimum_font_size(200.0f64, 1000), Some(18.0));
+        assert_eq!(minimum_font_size(27.5f64, 300), None);
+    }
+
+    #[test]
+    fn test_low_contrast_clips_to_zero() {
+        assert_eq!(contrast(&gray(0x80), &gray(0x82)), 0.0);
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{Luminance, Rgb};
+use ::bevy_color::{Color, LinearRgba, Srgba};
+
+/// sRGB-encoded colors from [Bevy](https://bevyengine.org), ignoring the alpha
+/// channel.
+impl Luminance<f32> for Srgba {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        Rgb::new(self.red, self.green, self.blue)
+    }
+}
+
+/// Linear colors from [Bevy](https://bevyengine.org), ignoring the alpha channel.
+///
+/// The channels are already linear, so they aren't decoded again by
+/// [`Luminance::linear_rgb`].
+impl Luminance<f32> for LinearRgba {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        Rgb::new(self.red, self.green, self.blue)
+    }
+
+    fn linear_rgb(&self) -> Rgb<f32> {
+        self.luminance_rgb()
+    }
+}
+
+/// Colors in any color space from [Bevy](https://bevyengine.org), ignoring the alpha
+/// channel.
+///
+/// The colors are converted to [`LinearRgba`].
+///
+/// ```
+/// use bevy_color::Color;
+/// use relative_luminance::Luminance;
+///
+/// let gray = Color::srgb(0.5, 0.5, 0.5);
+/// let linear_gray = Color::linear_rgb(0.214, 0.214, 0.214);
+///
+/// assert!((gray.wcag_relative_luminance() - 0.214).abs() < 0.001);
+/// assert!((linear_gray.wcag_relative_luminance() - 0.214).abs() < 0.001);
+/// ```
+impl Luminance<f32> for Color {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        LinearRgba::from(*self).luminance_rgb()
+    }
+
+    fn linear_rgb(&self) -> Rgb<f32> {
+        self.luminance_rgb()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encoded_and_linear_agree() {
+        let encoded = Srgba::new(0.2, 0.5, 0.8, 1.0);
+        let linear = LinearRgba::from(encoded);
+        let difference = encoded.wcag_relative_luminance() - linear.wcag_relative_luminance();
+        assert!(difference.abs() < 1e-4);
+        assert!(
+            (linear.relative_luminance() - Color::from(encoded).relative_luminance()).abs() < 1e-6
+        );
+    }
+}
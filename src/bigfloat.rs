@@ -0,0 +1,158 @@
+use crate::{Float, LuminanceValue};
+use core::ops::Mul;
+use num_bigfloat::BigFloat;
+
+/// An exact decimal weight for [`BigFloat`] channels from the
+/// [`num-bigfloat`](num_bigfloat) crate.
+///
+/// `BigFloat`s can't be created in constants, so the weights of `BigFloat` are stored
+/// as fractions, and are only converted when they are multiplied.
+///
+/// ```
+/// use num_bigfloat::BigFloat;
+/// use relative_luminance::{BigFloatWeight, Luminance, Rgb, Weights};
+///
+/// let one = BigFloat::from(1u8);
+/// let zero = BigFloat::from(0u8);
+/// let green: Rgb<BigFloat> = Rgb::new(zero, one, zero);
+///
+/// assert_eq!(green.relative_luminance(), BigFloat::parse("0.7152").unwrap());
+///
+/// let weights = Weights::<BigFloat>::new(
+///     BigFloatWeight::new(1, 3),
+///     BigFloatWeight::new(1, 3),
+///     BigFloatWeight::new(1, 3),
+/// );
+/// let third = one / BigFloat::from(3u8);
+///
+/// assert_eq!(green.relative_luminance_with(&weights), third);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BigFloatWeight {
+    numerator: u64,
+    denominator: u64,
+}
+
+impl BigFloatWeight {
+    /// Creates the weight `numerator / denominator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is 0.
+    pub const fn new(numerator: u64, denominator: u64) -> Self {
+        assert!(denominator != 0, "denominator must not be 0");
+        BigFloatWeight {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Converts the weight to a `BigFloat`.
+    pub fn to_big_float(&self) -> BigFloat {
+        BigFloat::from(self.numerator) / BigFloat::from(self.denominator)
+    }
+}
+
+impl Mul<BigFloatWeight> for BigFloat {
+    type Output = BigFloat;
+
+    fn mul(self, weight: BigFloatWeight) -> BigFloat {
+        self * BigFloat::from(weight.numerator) / BigFloat::from(weight.denominator)
+    }
+}
+
+/// Arbitrary-precision channels from the [`num-bigfloat`](num_bigfloat) crate.
+impl LuminanceValue for BigFloat {
+    type Channel = BigFloat;
+    type Weight = BigFloatWeight;
+    type Weighted = BigFloat;
+    const RED_WEIGHT: BigFloatWeight = BigFloatWeight::new(2126, 10000);
+    const GREEN_WEIGHT: BigFloatWeight = BigFloatWeight::new(7152, 10000);
+    const BLUE_WEIGHT: BigFloatWeight = BigFloatWeight::new(722, 10000);
+}
+
+/// Note that constants, like the thresholds of [`transfer::Srgb`](crate::transfer::Srgb),
+/// are converted from `f64`.
+impl Float for BigFloat {
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        BigFloat::from_f64(value)
+    }
+
+    #[inline]
+    fn powf(self, n: Self) -> Self {
+        self.pow(&n)
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        BigFloat::abs(&self)
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        BigFloat::exp(&self)
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        BigFloat::ln(&self)
+    }
+
+    #[inline]
+    fn floor(self) -> Self {
+        BigFloat::floor(&self)
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        BigFloat::sin(&self)
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        BigFloat::cos(&self)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        let zero = BigFloat::from(0u8);
+        if other > zero {
+            (self / other).atan()
+        } else if other < zero && self >= zero {
+            (self / other).atan() + num_bigfloat::PI
+        } else if other < zero {
+            (self / other).atan() - num_bigfloat::PI
+        } else if self > zero {
+            num_bigfloat::HALF_PI
+        } else if self < zero {
+            -num_bigfloat::HALF_PI
+        } else {
+            zero
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Luminance, Rgb};
+
+    #[test]
+    fn test_matches_f64() {
+        let big = Rgb::<BigFloat>::new(
+            BigFloat::from_f64(0.2),
+            BigFloat::from_f64(0.4),
+            BigFloat::from_f64(0.6),
+        );
+        let float = Rgb::<f64>::new(0.2, 0.4, 0.6);
+        let difference = big.wcag_relative_luminance().to_f64() - float.wcag_relative_luminance();
+        assert!(difference.abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_white_is_exactly_one() {
+        let one = BigFloat::from(1u8);
+        let white = Rgb::<BigFloat>::new(one, one, one);
+        assert_eq!(white.relative_luminance(), one);
+    }
+}
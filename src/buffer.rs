@@ -0,0 +1,342 @@
+//! Luminance of raw pixel buffers, like screenshots and framebuffers.
+//!
+//! ```
+//! use relative_luminance::buffer::{self, PixelFormat};
+//!
+//! // A 2×2 image of black, white, red, and blue pixels
+//! let pixels = [0, 0, 0, 255, 255, 255, 255, 0, 0, 0, 0, 255];
+//! let luminances: Vec<f32> =
+//!     buffer::luminance_of_buffer(&pixels, PixelFormat::Rgb8, 2, 2, 6)
+//!         .unwrap()
+//!         .collect();
+//!
+//! assert_eq!(luminances, [0.0, 1.0, 0.2126, 0.0722]);
+//! ```
+use crate::video::{self, Range};
+use crate::Rgb;
+use core::fmt;
+
+/// The layout of the bytes of each pixel.
+///
+/// Formats are named in the order of the bytes in memory, so [`PixelFormat::Bgra8`]
+/// starts with the blue byte.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PixelFormat {
+    /// Red, green, and blue bytes.
+    Rgb8,
+    /// Blue, green, and red bytes, as used by OpenCV.
+    Bgr8,
+    /// Red, green, blue, and alpha bytes.
+    Rgba8,
+    /// Blue, green, red, and alpha bytes, as used by many framebuffers.
+    Bgra8,
+    /// Alpha, red, green, and blue bytes.
+    Argb8,
+}
+
+impl PixelFormat {
+    /// Gets the number of bytes in each pixel.
+    pub const fn bytes_per_pixel(&self) -> usize {
+        match self {
+            PixelFormat::Rgb8 | PixelFormat::Bgr8 => 3,
+            PixelFormat::Rgba8 | PixelFormat::Bgra8 | PixelFormat::Argb8 => 4,
+        }
+    }
+
+    /// Gets the positions of the red, green, and blue bytes in a pixel.
+    const fn offsets(&self) -> [usize; 3] {
+        match self {
+            PixelFormat::Rgb8 | PixelFormat::Rgba8 => [0, 1, 2],
+            PixelFormat::Bgr8 | PixelFormat::Bgra8 => [2, 1, 0],
+            PixelFormat::Argb8 => [1, 2, 3],
+        }
+    }
+}
+
+/// An error from reading a pixel buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BufferError {
+    /// The stride is smaller than a row of pixels.
+    StrideTooSmall,
+    /// The buffer is smaller than the image.
+    BufferTooSmall,
+}
+
+impl fmt::Display for BufferError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BufferError::StrideTooSmall => write!(f, "stride is smaller than a row of pixels"),
+            BufferError::BufferTooSmall => write!(f, "buffer is smaller than the image"),
+        }
+    }
+}
+
+impl std::error::Error for BufferError {}
+
+/// Gets the relative luminance of every pixel in a buffer, row by row.
+///
+/// `stride` is the number of bytes from the start of one row to the start of the next,
+/// which may include padding. The last row doesn't need to include its padding. The
+/// channels are treated like [`Rgb<f32>`] in the range [0.0, 1.0].
+///
+/// Alpha channels are ignored, like with [`Rgba`](crate::Rgba).
+pub fn luminance_of_buffer(
+    bytes: &[u8],
+    format: PixelFormat,
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Result<BufferLuminance<'_>, BufferError> {
+    // Sizes that overflow can't fit in any buffer.
+    let row_size = width
+        .checked_mul(format.bytes_per_pixel())
+        .ok_or(BufferError::BufferTooSmall)?;
+    if stride < row_size {
+        return Err(BufferError::StrideTooSmall);
+    }
+    let image_size = match height.checked_sub(1) {
+        Some(rows) => stride
+            .checked_mul(rows)
+            .and_then(|size| size.checked_add(row_size))
+            .ok_or(BufferError::BufferTooSmall)?,
+        None => 0,
+    };
+    if bytes.len() < image_size {
+        return Err(BufferError::BufferTooSmall);
+    }
+    Ok(BufferLuminance {
+        bytes,
+        format,
+        width,
+        stride,
+        index: 0,
+        len: width * height,
+    })
+}
+
+/// An iterator over the luminances of the pixels in a buffer.
+///
+/// This is created by [`luminance_of_buffer`].
+#[derive(Clone, Debug)]
+pub struct BufferLuminance<'a> {
+    bytes: &'a [u8],
+    format: PixelFormat,
+    width: usize,
+    stride: usize,
+    index: usize,
+    len: usize,
+}
+
+impl Iterator for BufferLuminance<'_> {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.index >= self.len {
+            return None;
+        }
+        let row = self.index / self.width;
+        let column = self.index % self.width;
+        let start = row * self.stride + column * self.format.bytes_per_pixel();
+        let [r, g, b] = self
+            .format
+            .offsets()
+            .map(|offset| f32::from(self.bytes[start + offset]) / 255.0);
+        self.index += 1;
+        Some(Rgb::<f32>::new(r, g, b).relative_luminance())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for BufferLuminance<'_> {}
+
+/// The layout of a planar 8-bit Y'CbCr frame.
+///
+/// Both formats start with a full-resolution luma (Y') plane, followed by chroma
+/// planes with half the width and height.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PlanarFormat {
+    /// A Y' plane, then a Cb plane and a Cr plane, each with half the stride.
+    I420,
+    /// A Y' plane, then a plane of interleaved Cb and Cr bytes with the same stride.
+    Nv12,
+}
+
+impl PlanarFormat {
+    /// Gets the size of a frame, in bytes, or [`None`] if it overflows.
+    fn frame_size(&self, height: usize, stride: usize) -> Option<usize> {
+        let chroma_height = height.div_ceil(2);
+        let chroma_size = match self {
+            PlanarFormat::I420 => stride
+                .div_ceil(2)
+                .checked_mul(chroma_height)?
+                .checked_mul(2)?,
+            PlanarFormat::Nv12 => stride.checked_mul(chroma_height)?,
+        };
+        stride.checked_mul(height)?.checked_add(chroma_size)
+    }
+}
+
+/// Gets the luma of every pixel in a planar frame, row by row.
+///
+/// Only the luma plane is read, so there's no conversion to RGB. Like
+/// [`YCbCr`](video::YCbCr), the luma is normalized to [0.0, 1.0] with the given
+/// range. `stride` is the number of bytes from the start of one luma row to the start
+/// of the next.
+///
+/// ```
+/// use relative_luminance::buffer::{self, PlanarFormat};
+/// use relative_luminance::video::Range;
+///
+/// // A 2×2 NV12 frame with black and white rows, and gray chroma
+/// let frame = [16, 16, 235, 235, 128, 128];
+/// let luminances: Vec<f32> =
+///     buffer::luminance_of_planar(&frame, PlanarFormat::Nv12, 2, 2, 2, Range::Limited)
+///         .unwrap()
+///         .collect();
+///
+/// assert_eq!(luminances, [0.0, 0.0, 1.0, 1.0]);
+/// ```
+pub fn luminance_of_planar(
+    bytes: &[u8],
+    format: PlanarFormat,
+    width: usize,
+    height: usize,
+    stride: usize,
+    range: Range,
+) -> Result<PlanarLuminance<'_>, BufferError> {
+    if stride < width {
+        return Err(BufferError::StrideTooSmall);
+    }
+    match format.frame_size(height, stride) {
+        Some(size) if size <= bytes.len() => {}
+        _ => return Err(BufferError::BufferTooSmall),
+    }
+    Ok(PlanarLuminance {
+        bytes,
+        range,
+        width,
+        stride,
+        index: 0,
+        len: width * height,
+    })
+}
+
+/// Gets the average luma of a planar frame.
+///
+/// See [`luminance_of_planar`]. An empty frame has an average of 0.0.
+pub fn average_luminance_of_planar(
+    bytes: &[u8],
+    format: PlanarFormat,
+    width: usize,
+    height: usize,
+    stride: usize,
+    range: Range,
+) -> Result<f32, BufferError> {
+    let luminances = luminance_of_planar(bytes, format, width, height, stride, range)?;
+    let len = luminances.len();
+    if len == 0 {
+        return Ok(0.0);
+    }
+    // Summing in f64 avoids losing precision over millions of pixels.
+    let sum: f64 = luminances.map(f64::from).sum();
+    Ok((sum / len as f64) as f32)
+}
+
+/// An iterator over the luma of the pixels in a planar frame.
+///
+/// This is created by [`luminance_of_planar`].
+#[derive(Clone, Debug)]
+pub struct PlanarLuminance<'a> {
+    bytes: &'a [u8],
+    range: Range,
+    width: usize,
+    stride: usize,
+    index: usize,
+    len: usize,
+}
+
+impl Iterator for PlanarLuminance<'_> {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.index >= self.len {
+            return None;
+        }
+        let row = self.index / self.width;
+        let column = self.index % self.width;
+        let luma = self.bytes[row * self.stride + column];
+        self.index += 1;
+        Some(video::normalize(u16::from(luma), 8, self.range))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for PlanarLuminance<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stride_padding() {
+        // 1×2 image of blue BGRA pixels, with 4 bytes of padding after each row
+        let pixels = [255, 0, 0, 255, 9, 9, 9, 9, 255, 0, 0, 255];
+        let luminances: Vec<_> = luminance_of_buffer(&pixels, PixelFormat::Bgra8, 1, 2, 8)
+            .unwrap()
+            .collect();
+        assert_eq!(luminances, [0.0722, 0.0722]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let pixels = [0; 12];
+        assert_eq!(
+            luminance_of_buffer(&pixels, PixelFormat::Rgb8, 2, 2, 5).unwrap_err(),
+            BufferError::StrideTooSmall
+        );
+        assert_eq!(
+            luminance_of_buffer(&pixels, PixelFormat::Rgba8, 2, 2, 8).unwrap_err(),
+            BufferError::BufferTooSmall
+        );
+    }
+
+    #[test]
+    fn test_overflowing_sizes() {
+        let pixels = [0; 12];
+        assert_eq!(
+            luminance_of_buffer(&pixels, PixelFormat::Rgba8, usize::MAX / 2, 1, usize::MAX)
+                .unwrap_err(),
+            BufferError::BufferTooSmall
+        );
+        assert_eq!(
+            luminance_of_buffer(&pixels, PixelFormat::Rgb8, 1, usize::MAX, 3).unwrap_err(),
+            BufferError::BufferTooSmall
+        );
+        for format in [PlanarFormat::I420, PlanarFormat::Nv12] {
+            let error = luminance_of_planar(&pixels, format, 2, usize::MAX, 2, Range::Full);
+            assert_eq!(error.unwrap_err(), BufferError::BufferTooSmall);
+        }
+    }
+
+    #[test]
+    fn test_planar_average() {
+        // 3×2 I420 frame with a stride of 4, so each chroma plane is 2×1
+        let frame = [0, 255, 0, 9, 255, 0, 255, 9, 128, 128, 128, 128];
+        let average =
+            average_luminance_of_planar(&frame, PlanarFormat::I420, 3, 2, 4, Range::Full).unwrap();
+        assert_eq!(average, 0.5);
+        assert_eq!(
+            luminance_of_planar(&frame[..11], PlanarFormat::I420, 3, 2, 4, Range::Full)
+                .unwrap_err(),
+            BufferError::BufferTooSmall
+        );
+    }
+}
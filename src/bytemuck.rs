@@ -0,0 +1,42 @@
+use crate::{LuminanceValue, PremultipliedRgba, Rgb, Rgba};
+use ::bytemuck::{Pod, Zeroable};
+
+// SAFETY: The colors are `repr(C)` structs of channels with the same type, so they
+// have no padding, and any bytes that are valid channels are valid colors.
+
+/// Lets buffers of channels, like `&[u8]` or `&[f32]`, be cast to `&[Rgb<T>]`.
+///
+/// ```
+/// use relative_luminance::{Luminance, Rgb};
+///
+/// let pixels: [u8; 6] = [255, 0, 0, 0, 255, 0];
+/// let pixels: &[Rgb<u8>] = bytemuck::cast_slice(&pixels);
+///
+/// assert_eq!(pixels[1].relative_luminance(), 0.7152);
+/// ```
+unsafe impl<T: LuminanceValue + 'static> Pod for Rgb<T> where T::Channel: Pod {}
+unsafe impl<T: LuminanceValue> Zeroable for Rgb<T> where T::Channel: Zeroable {}
+
+/// Lets buffers of channels, like `&[u8]` or `&[f32]`, be cast to `&[Rgba<T>]`.
+unsafe impl<T: LuminanceValue + 'static> Pod for Rgba<T> where T::Channel: Pod {}
+unsafe impl<T: LuminanceValue> Zeroable for Rgba<T> where T::Channel: Zeroable {}
+
+/// Lets buffers of channels, like `&[u8]` or `&[f32]`, be cast to
+/// `&[PremultipliedRgba<T>]`.
+unsafe impl<T: LuminanceValue + 'static> Pod for PremultipliedRgba<T> where T::Channel: Pod {}
+unsafe impl<T: LuminanceValue> Zeroable for PremultipliedRgba<T> where T::Channel: Zeroable {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cast_rgba() {
+        let channels = [0.0f32, 0.0, 1.0, 0.5, 1.0, 1.0, 1.0, 1.0];
+        let pixels: &[Rgba<f32>] = ::bytemuck::cast_slice(&channels);
+        assert_eq!(pixels.len(), 2);
+        assert_eq!((pixels[0].b, pixels[0].a), (1.0, 0.5));
+        let zero: Rgb<u16> = Zeroable::zeroed();
+        assert_eq!(zero.relative_luminance(), 0.0);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{Float, Luminance, LuminanceValue, PremultipliedRgba, Rgb, Rgba};
+use ::cint::{Alpha, ColorInterop, EncodedSrgb, LinearSrgb, PremultipliedAlpha};
+
+impl<T: LuminanceValue> From<Rgb<T>> for EncodedSrgb<T::Channel> {
+    fn from(Rgb { r, g, b }: Rgb<T>) -> Self {
+        EncodedSrgb { r, g, b }
+    }
+}
+
+impl<T: LuminanceValue> From<EncodedSrgb<T::Channel>> for Rgb<T> {
+    fn from(EncodedSrgb { r, g, b }: EncodedSrgb<T::Channel>) -> Self {
+        Rgb::new(r, g, b)
+    }
+}
+
+/// The channels are sRGB-encoded, like the channels of [`Rgb`] usually are.
+///
+/// ```
+/// use relative_luminance::{Luminance, Rgb};
+///
+/// let red: cint::EncodedSrgb<f32> = Rgb::<f32>::new(1.0, 0.0, 0.0).into();
+/// let red: Rgb<f32> = red.into();
+///
+/// assert_eq!(red.relative_luminance(), 0.2126);
+/// ```
+impl<T: LuminanceValue> ColorInterop for Rgb<T> {
+    type CintTy = EncodedSrgb<T::Channel>;
+}
+
+impl<T: LuminanceValue> From<Rgba<T>> for Alpha<EncodedSrgb<T::Channel>> {
+    fn from(Rgba { r, g, b, a }: Rgba<T>) -> Self {
+        Alpha {
+            color: EncodedSrgb { r, g, b },
+            alpha: a,
+        }
+    }
+}
+
+impl<T: LuminanceValue> From<Alpha<EncodedSrgb<T::Channel>>> for Rgba<T> {
+    fn from(Alpha { color, alpha }: Alpha<EncodedSrgb<T::Channel>>) -> Self {
+        Rgba::new(color.r, color.g, color.b, alpha)
+    }
+}
+
+impl<T: LuminanceValue> ColorInterop for Rgba<T> {
+    type CintTy = Alpha<EncodedSrgb<T::Channel>>;
+}
+
+impl<T: LuminanceValue> From<PremultipliedRgba<T>> for PremultipliedAlpha<EncodedSrgb<T::Channel>> {
+    fn from(PremultipliedRgba { r, g, b, a }: PremultipliedRgba<T>) -> Self {
+        PremultipliedAlpha {
+            color: EncodedSrgb { r, g, b },
+            alpha: a,
+        }
+    }
+}
+
+impl<T: LuminanceValue> From<PremultipliedAlpha<EncodedSrgb<T::Channel>>> for PremultipliedRgba<T> {
+    fn from(
+        PremultipliedAlpha { color, alpha }: PremultipliedAlpha<EncodedSrgb<T::Channel>>,
+    ) -> Self {
+        PremultipliedRgba::new(color.r, color.g, color.b, alpha)
+    }
+}
+
+impl<T: LuminanceValue> ColorInterop for PremultipliedRgba<T> {
+    type CintTy = PremultipliedAlpha<EncodedSrgb<T::Channel>>;
+}
+
+/// sRGB-encoded colors from the [`cint`](::cint) crate.
+impl<T: LuminanceValue<Channel = T>> Luminance<T> for EncodedSrgb<T> {
+    fn luminance_rgb(&self) -> Rgb<T> {
+        Rgb::new(self.r, self.g, self.b)
+    }
+}
+
+/// Linear sRGB colors from the [`cint`](::cint) crate.
+///
+/// The channels are already linear, so they aren't decoded again by
+/// [`Luminance::linear_rgb`].
+impl<T: LuminanceValue<Channel = T>> Luminance<T> for LinearSrgb<T> {
+    fn luminance_rgb(&self) -> Rgb<T> {
+        Rgb::new(self.r, self.g, self.b)
+    }
+
+    fn linear_rgb(&self) -> Rgb<T>
+    where
+        T::Channel: Float,
+    {
+        self.luminance_rgb()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let color = Rgba::<u8>::new(1, 2, 3, 4);
+        let round_trip = Rgba::<u8>::from_cint(color.into_cint());
+        assert_eq!(
+            (round_trip.r, round_trip.g, round_trip.b, round_trip.a),
+            (1, 2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn test_linear_not_decoded() {
+        let linear = LinearSrgb {
+            r: 0.5f64,
+            g: 0.5,
+            b: 0.5,
+        };
+        assert!((linear.wcag_relative_luminance() - 0.5).abs() < 1e-12);
+        let encoded = EncodedSrgb {
+            r: 0.5f64,
+            g: 0.5,
+            b: 0.5,
+        };
+        assert!((encoded.wcag_relative_luminance() - 0.214).abs() < 0.001);
+    }
+}
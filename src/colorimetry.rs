@@ -0,0 +1,294 @@
+//! Deriving luminance weights from the definition of an RGB color space.
+//!
+//! The luminance weights of an RGB color space are the Y row of its RGB to XYZ matrix,
+//! which can be calculated from the chromaticities of its primaries and white point.
+//!
+//! ```
+//! use relative_luminance::colorimetry::{Chromaticity, Primaries};
+//!
+//! // The primaries of sRGB / BT.709
+//! let srgb = Primaries {
+//!     red: Chromaticity::new(0.64, 0.33),
+//!     green: Chromaticity::new(0.30, 0.60),
+//!     blue: Chromaticity::new(0.15, 0.06),
+//!     white: Chromaticity::<f64>::D65,
+//! };
+//! let weights = srgb.weights();
+//!
+//! assert!((weights.r - 0.2126).abs() < 0.0001);
+//! assert!((weights.g - 0.7152).abs() < 0.0001);
+//! assert!((weights.b - 0.0722).abs() < 0.0001);
+//! ```
+use crate::{Float, LuminanceValue, Weights};
+
+/// A 3×3 matrix in row-major order.
+pub type Matrix3<F> = [[F; 3]; 3];
+
+/// The matrix converting linear sRGB to CIE XYZ with a D65 white point.
+pub(crate) const SRGB_TO_XYZ: Matrix3<f64> = [
+    [0.4123908, 0.3575843, 0.1804808],
+    [0.2126390, 0.7151687, 0.0721923],
+    [0.0193308, 0.1191948, 0.9505322],
+];
+
+/// CIE 1931 xy chromaticity coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Chromaticity<F> {
+    pub x: F,
+    pub y: F,
+}
+
+impl<F> Chromaticity<F> {
+    /// Creates a new `Chromaticity<F>`.
+    pub const fn new(x: F, y: F) -> Self {
+        Chromaticity { x, y }
+    }
+}
+
+impl<F: Float> Chromaticity<F> {
+    /// Gets the XYZ tristimulus values of this chromaticity, with a luminance (Y) of
+    /// 1.0.
+    pub fn to_xyz(&self) -> [F; 3] {
+        let one = F::from_f64(1.0);
+        [self.x / self.y, one, (one - self.x - self.y) / self.y]
+    }
+}
+
+macro_rules! impl_white_points {
+    ($($t:ty),*) => {
+        $(
+            impl Chromaticity<$t> {
+                /// The CIE standard illuminant D65, as used by sRGB, Display P3, and
+                /// most video standards.
+                pub const D65: Self = Chromaticity::new(0.3127, 0.3290);
+                /// The CIE standard illuminant D50, as used by ICC profiles and
+                /// ProPhoto RGB.
+                pub const D50: Self = Chromaticity::new(0.3457, 0.3585);
+            }
+        )*
+    };
+}
+
+impl_white_points!(f32, f64);
+
+/// The chromaticities of the primaries and white point of an RGB color space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Primaries<F> {
+    pub red: Chromaticity<F>,
+    pub green: Chromaticity<F>,
+    pub blue: Chromaticity<F>,
+    pub white: Chromaticity<F>,
+}
+
+impl<F: Float> Primaries<F> {
+    /// Gets the matrix converting linear RGB in this color space to CIE XYZ.
+    ///
+    /// The matrix is normalized so that the white point has a luminance of 1.0.
+    pub fn rgb_to_xyz(&self) -> Matrix3<F> {
+        let [xr, yr, zr] = self.red.to_xyz();
+        let [xg, yg, zg] = self.green.to_xyz();
+        let [xb, yb, zb] = self.blue.to_xyz();
+        let primaries = [[xr, xg, xb], [yr, yg, yb], [zr, zg, zb]];
+        let [sr, sg, sb] = multiply(&invert(&primaries), self.white.to_xyz());
+        [
+            [xr * sr, xg * sg, xb * sb],
+            [yr * sr, yg * sg, yb * sb],
+            [zr * sr, zg * sg, zb * sb],
+        ]
+    }
+
+    /// Gets the luminance weights of this color space.
+    pub fn weights(&self) -> Weights<F>
+    where
+        F: LuminanceValue<Weight = F>,
+    {
+        let [_, [r, g, b], _] = self.rgb_to_xyz();
+        Weights { r, g, b }
+    }
+
+    /// Gets the matrix converting linear RGB in this color space to CIE XYZ relative to
+    /// a different white point, using the [Bradford][bradford] chromatic adaptation
+    /// transform.
+    ///
+    /// This is how ICC profiles, which are relative to D50, describe D65 color spaces
+    /// like sRGB.
+    ///
+    /// [bradford]: http://www.brucelindbloom.com/index.html?Eqn_ChromAdapt.html
+    pub fn rgb_to_xyz_adapted(&self, white: Chromaticity<F>) -> Matrix3<F> {
+        multiply_matrices(&bradford(self.white, white), &self.rgb_to_xyz())
+    }
+
+    /// Gets the luminance weights of this color space when viewed under a different
+    /// white point, using the Bradford chromatic adaptation transform.
+    ///
+    /// ```
+    /// use relative_luminance::colorimetry::{Chromaticity, Primaries};
+    ///
+    /// let srgb = Primaries {
+    ///     red: Chromaticity::new(0.64, 0.33),
+    ///     green: Chromaticity::new(0.30, 0.60),
+    ///     blue: Chromaticity::new(0.15, 0.06),
+    ///     white: Chromaticity::<f64>::D65,
+    /// };
+    /// // The weights of sRGB in a D50 ICC profile
+    /// let weights = srgb.weights_adapted(Chromaticity::<f64>::D50);
+    ///
+    /// assert!((weights.r - 0.2225).abs() < 0.0001);
+    /// assert!((weights.g - 0.7169).abs() < 0.0001);
+    /// assert!((weights.b - 0.0606).abs() < 0.0001);
+    /// ```
+    pub fn weights_adapted(&self, white: Chromaticity<F>) -> Weights<F>
+    where
+        F: LuminanceValue<Weight = F>,
+    {
+        let [_, [r, g, b], _] = self.rgb_to_xyz_adapted(white);
+        Weights { r, g, b }
+    }
+}
+
+/// The Bradford cone response matrix.
+const BRADFORD: Matrix3<f64> = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+/// Gets the [Bradford][bradford] chromatic adaptation matrix, which converts CIE XYZ
+/// relative to one white point to CIE XYZ relative to another.
+///
+/// ```
+/// use relative_luminance::colorimetry::{bradford, Chromaticity};
+///
+/// let identity = bradford(Chromaticity::<f64>::D65, Chromaticity::<f64>::D65);
+///
+/// assert!((identity[0][0] - 1.0).abs() < 1e-12);
+/// assert!(identity[0][1].abs() < 1e-12);
+/// ```
+///
+/// [bradford]: http://www.brucelindbloom.com/index.html?Eqn_ChromAdapt.html
+pub fn bradford<F: Float>(source: Chromaticity<F>, destination: Chromaticity<F>) -> Matrix3<F> {
+    let cone_response = BRADFORD.map(|row| row.map(F::from_f64));
+    let [source_rho, source_gamma, source_beta] = multiply(&cone_response, source.to_xyz());
+    let [destination_rho, destination_gamma, destination_beta] =
+        multiply(&cone_response, destination.to_xyz());
+    let zero = F::from_f64(0.0);
+    let scale = [
+        [destination_rho / source_rho, zero, zero],
+        [zero, destination_gamma / source_gamma, zero],
+        [zero, zero, destination_beta / source_beta],
+    ];
+    multiply_matrices(
+        &invert(&cone_response),
+        &multiply_matrices(&scale, &cone_response),
+    )
+}
+
+/// Multiplies two matrices.
+pub(crate) fn multiply_matrices<F: Float>(a: &Matrix3<F>, b: &Matrix3<F>) -> Matrix3<F> {
+    let column = |j: usize| multiply(a, [b[0][j], b[1][j], b[2][j]]);
+    let [c0, c1, c2] = [column(0), column(1), column(2)];
+    [
+        [c0[0], c1[0], c2[0]],
+        [c0[1], c1[1], c2[1]],
+        [c0[2], c1[2], c2[2]],
+    ]
+}
+
+/// The matrix converting CIE XYZ with a D65 white point to linear sRGB.
+pub(crate) const XYZ_TO_SRGB: Matrix3<f64> = [
+    [3.2409699, -1.5373832, -0.4986108],
+    [-0.9692436, 1.8759675, 0.0415551],
+    [0.0556301, -0.2039770, 1.0569715],
+];
+
+/// Converts linear sRGB to CIE XYZ.
+pub(crate) fn srgb_to_xyz<F: Float>(rgb: [F; 3]) -> [F; 3] {
+    multiply(&SRGB_TO_XYZ.map(|row| row.map(F::from_f64)), rgb)
+}
+
+/// Converts CIE XYZ to linear sRGB.
+pub(crate) fn xyz_to_srgb<F: Float>(xyz: [F; 3]) -> [F; 3] {
+    multiply(&XYZ_TO_SRGB.map(|row| row.map(F::from_f64)), xyz)
+}
+
+/// Multiplies a matrix and a column vector.
+pub(crate) fn multiply<F: Float>(matrix: &Matrix3<F>, vector: [F; 3]) -> [F; 3] {
+    let row =
+        |i: usize| matrix[i][0] * vector[0] + matrix[i][1] * vector[1] + matrix[i][2] * vector[2];
+    [row(0), row(1), row(2)]
+}
+
+/// Inverts a matrix with the adjugate method.
+pub(crate) fn invert<F: Float>(m: &Matrix3<F>) -> Matrix3<F> {
+    let cofactor =
+        |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+    let adjugate = [
+        [
+            cofactor(1, 2, 1, 2),
+            cofactor(0, 2, 2, 1),
+            cofactor(0, 1, 1, 2),
+        ],
+        [
+            cofactor(1, 2, 2, 0),
+            cofactor(0, 2, 0, 2),
+            cofactor(0, 1, 2, 0),
+        ],
+        [
+            cofactor(1, 2, 0, 1),
+            cofactor(0, 2, 1, 0),
+            cofactor(0, 1, 0, 1),
+        ],
+    ];
+    let determinant =
+        m[0][0] * adjugate[0][0] + m[0][1] * adjugate[1][0] + m[0][2] * adjugate[2][0];
+    adjugate.map(|row| row.map(|value| value / determinant))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invert() {
+        let m = [[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]];
+        let inverse = invert(&m);
+        let v = [1.0, -2.0, 0.5];
+        let round_trip = multiply(&m, multiply(&inverse, v));
+        for (value, expected) in round_trip.iter().zip(v) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_xyz_to_srgb_is_inverse() {
+        let rgb = [0.25, 0.5, 0.75];
+        let round_trip = xyz_to_srgb(srgb_to_xyz(rgb));
+        for (value, expected) in round_trip.iter().zip(rgb) {
+            assert!((value - expected).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_bradford_maps_white_to_white() {
+        let adaptation = bradford(Chromaticity::<f64>::D65, Chromaticity::<f64>::D50);
+        let adapted = multiply(&adaptation, Chromaticity::<f64>::D65.to_xyz());
+        for (value, expected) in adapted.iter().zip(Chromaticity::<f64>::D50.to_xyz()) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_srgb_matrix() {
+        let srgb = Primaries {
+            red: Chromaticity::new(0.64, 0.33),
+            green: Chromaticity::new(0.30, 0.60),
+            blue: Chromaticity::new(0.15, 0.06),
+            white: Chromaticity::<f64>::D65,
+        };
+        for (row, expected) in srgb.rgb_to_xyz().iter().zip(SRGB_TO_XYZ) {
+            for (value, expected) in row.iter().zip(expected) {
+                assert!((value - expected).abs() < 1e-6);
+            }
+        }
+    }
+}
@@ -0,0 +1,835 @@
+//! Luminance contrast measures from vision science.
+//!
+//! These use [`Luminance::wcag_relative_luminance`], like the WCAG
+//! [contrast ratio](Luminance::contrast_ratio).
+//!
+//! ```
+//! use relative_luminance::{contrast, Rgb};
+//!
+//! let black: Rgb<f64> = Rgb::new(0.0, 0.0, 0.0);
+//! let white: Rgb<f64> = Rgb::new(1.0, 1.0, 1.0);
+//!
+//! assert!((contrast::michelson(&black, &white) - 1.0).abs() < 1e-9);
+//! assert!((contrast::weber(&black, &white) + 1.0).abs() < 1e-9);
+//! ```
+use crate::polar::{clamp_unit, gamut_map_oklch, search};
+use crate::transfer::Srgb;
+use crate::{apca, Float, Luminance, LuminanceValue, Oklab, Oklch, Rgb};
+use core::fmt;
+
+/// Gets the [Michelson contrast][michelson] `(Lmax − Lmin) / (Lmax + Lmin)` between two
+/// colors.
+///
+/// This is commonly used for periodic patterns, like gratings, with equal amounts of
+/// both colors. The result is in the range [0.0, 1.0], and doesn't depend on which of
+/// the two colors is lighter.
+///
+/// ```
+/// use relative_luminance::{contrast, Rgb};
+///
+/// let gray: Rgb<f64> = Rgb::new(0.5, 0.5, 0.5);
+/// let white: Rgb<f64> = Rgb::new(1.0, 1.0, 1.0);
+///
+/// assert_eq!(contrast::michelson(&gray, &white), contrast::michelson(&white, &gray));
+/// assert_eq!(contrast::michelson(&white, &white), 0.0);
+/// ```
+///
+/// [michelson]: https://en.wikipedia.org/wiki/Contrast_(vision)#Michelson_contrast
+pub fn michelson<T, A, B>(a: &A, b: &B) -> T::Weighted
+where
+    T: LuminanceValue,
+    T::Channel: Float,
+    T::Weighted: Float,
+    A: Luminance<T> + ?Sized,
+    B: Luminance<T> + ?Sized,
+{
+    michelson_from_luminance(a.wcag_relative_luminance(), b.wcag_relative_luminance())
+}
+
+/// Gets the Michelson contrast from two relative luminances.
+///
+/// Two black luminances have a contrast of 0.0.
+///
+/// ```
+/// use relative_luminance::contrast;
+///
+/// assert_eq!(contrast::michelson_from_luminance(0.25f32, 0.75), 0.5);
+/// assert_eq!(contrast::michelson_from_luminance(0.0f32, 0.0), 0.0);
+/// ```
+pub fn michelson_from_luminance<F: Float>(a: F, b: F) -> F {
+    let (max, min) = if a >= b { (a, b) } else { (b, a) };
+    let sum = max + min;
+    if sum <= F::from_f64(0.0) {
+        return F::from_f64(0.0);
+    }
+    (max - min) / sum
+}
+
+/// Gets the [Weber contrast][weber] `(L − Lb) / Lb` of a small target on a large
+/// background.
+///
+/// Targets darker than the background have a negative contrast down to -1.0, and
+/// lighter targets have a positive contrast with no upper bound.
+///
+/// ```
+/// use relative_luminance::{contrast, Luminance, Rgb};
+///
+/// let gray: Rgb<f64> = Rgb::new(0.5, 0.5, 0.5);
+/// let white: Rgb<f64> = Rgb::new(1.0, 1.0, 1.0);
+///
+/// assert!(contrast::weber(&gray, &white) < 0.0);
+/// assert!(contrast::weber(&white, &gray) > 0.0);
+/// ```
+///
+/// [weber]: https://en.wikipedia.org/wiki/Contrast_(vision)#Weber_contrast
+pub fn weber<T, Target, Background>(target: &Target, background: &Background) -> T::Weighted
+where
+    T: LuminanceValue,
+    T::Channel: Float,
+    T::Weighted: Float,
+    Target: Luminance<T> + ?Sized,
+    Background: Luminance<T> + ?Sized,
+{
+    weber_from_luminance(
+        target.wcag_relative_luminance(),
+        background.wcag_relative_luminance(),
+    )
+}
+
+/// Gets the Weber contrast from the relative luminances of a target and its
+/// background.
+///
+/// The contrast on a black background is infinite, or NaN for a black target.
+///
+/// ```
+/// use relative_luminance::contrast;
+///
+/// assert_eq!(contrast::weber_from_luminance(0.75f32, 0.5), 0.5);
+/// assert!(contrast::weber_from_luminance(0.5f32, 0.0).is_infinite());
+/// ```
+pub fn weber_from_luminance<F: Float>(target: F, background: F) -> F {
+    (target - background) / background
+}
+
+/// Gets the WCAG contrast ratio from two relative luminances.
+///
+/// See [`Luminance::contrast_ratio`].
+///
+/// ```
+/// use relative_luminance::contrast;
+///
+/// assert_eq!(contrast::ratio_from_luminance(0.2f64, 0.45), 2.0);
+/// assert_eq!(contrast::ratio_from_luminance(0.45f64, 0.2), 2.0);
+/// ```
+pub fn ratio_from_luminance<F: Float>(a: F, b: F) -> F {
+    let (lighter, darker) = if a >= b { (a, b) } else { (b, a) };
+    let offset = F::from_f64(0.05);
+    (lighter + offset) / (darker + offset)
+}
+
+/// Gets black or white, whichever has the higher [contrast ratio](Luminance::contrast_ratio)
+/// with a background color.
+///
+/// Black is chosen if both have the same contrast ratio. See [`text_color_with`] to
+/// choose between other colors.
+///
+/// ```
+/// use relative_luminance::{contrast, Rgb};
+///
+/// let green: Rgb<f32> = Rgb::new(0.0, 1.0, 0.0);
+/// let blue: Rgb<f32> = Rgb::new(0.0, 0.0, 1.0);
+///
+/// assert_eq!(contrast::text_color(&green).g, 0.0);
+/// assert_eq!(contrast::text_color(&blue).g, 1.0);
+/// ```
+pub fn text_color<F, B>(background: &B) -> Rgb<F>
+where
+    F: Float + LuminanceValue<Channel = F, Weighted = F>,
+    B: Luminance<F> + ?Sized,
+{
+    let f = F::from_f64;
+    let black = Rgb::new(f(0.0), f(0.0), f(0.0));
+    let white = Rgb::new(f(1.0), f(1.0), f(1.0));
+    *text_color_with(background, &black, &white)
+}
+
+/// Gets the dark or the light color, whichever has the higher
+/// [contrast ratio](Luminance::contrast_ratio) with a background color.
+///
+/// The dark color is chosen if both have the same contrast ratio.
+///
+/// ```
+/// use relative_luminance::{contrast, Rgb};
+///
+/// let navy: Rgb<f32> = Rgb::new(0.0, 0.0, 0.5);
+/// let cream: Rgb<f32> = Rgb::new(1.0, 0.99, 0.82);
+/// let green: Rgb<f32> = Rgb::new(0.0, 1.0, 0.0);
+/// let purple: Rgb<f32> = Rgb::new(0.4, 0.0, 0.6);
+///
+/// assert_eq!(contrast::text_color_with(&green, &navy, &cream).b, 0.5);
+/// assert_eq!(contrast::text_color_with(&purple, &navy, &cream).b, 0.82);
+/// ```
+pub fn text_color_with<'a, T, B, C>(background: &B, dark: &'a C, light: &'a C) -> &'a C
+where
+    T: LuminanceValue,
+    T::Channel: Float,
+    T::Weighted: Float,
+    B: Luminance<T> + ?Sized,
+    C: Luminance<T> + ?Sized,
+{
+    if background.contrast_ratio(dark) >= background.contrast_ratio(light) {
+        dark
+    } else {
+        light
+    }
+}
+
+/// How [`pick_foreground_with`] chooses between candidates with the same contrast
+/// ratio.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TieBreak {
+    /// Choose the candidate that comes first.
+    #[default]
+    First,
+    /// Choose the candidate that comes last.
+    Last,
+    /// Choose the candidate with the lowest relative luminance.
+    Darkest,
+    /// Choose the candidate with the highest relative luminance.
+    Lightest,
+}
+
+/// Gets the candidate with the highest [contrast ratio](Luminance::contrast_ratio)
+/// with a background color, or [`None`] if there are no candidates.
+///
+/// The first candidate is chosen if several have the same contrast ratio. See
+/// [`pick_foreground_with`] to break ties in other ways.
+///
+/// ```
+/// use relative_luminance::{contrast, Rgb};
+///
+/// let brand: [Rgb<f32>; 3] = [
+///     Rgb::new(0.9, 0.3, 0.1),
+///     Rgb::new(0.1, 0.2, 0.5),
+///     Rgb::new(0.95, 0.95, 0.9),
+/// ];
+/// let background: Rgb<f32> = Rgb::new(0.2, 0.2, 0.2);
+///
+/// assert_eq!(contrast::pick_foreground(&background, &brand).unwrap().b, 0.9);
+/// ```
+pub fn pick_foreground<'a, T, B, C>(background: &B, candidates: &'a [C]) -> Option<&'a C>
+where
+    T: LuminanceValue,
+    T::Channel: Float,
+    T::Weighted: Float,
+    B: Luminance<T> + ?Sized,
+    C: Luminance<T>,
+{
+    pick_foreground_with(background, candidates, TieBreak::First)
+}
+
+/// Gets the candidate with the highest [contrast ratio](Luminance::contrast_ratio)
+/// with a background color, breaking ties with a [`TieBreak`].
+///
+/// ```
+/// use relative_luminance::contrast::{self, TieBreak};
+/// use relative_luminance::Rgb;
+///
+/// let red: Rgb<f64> = Rgb::new(1.0, 0.0, 0.0);
+/// let candidates: [Rgb<f64>; 2] = [Rgb::new(0.0, 0.0, 0.0), Rgb::new(0.0, 0.0, 0.0)];
+///
+/// let last = contrast::pick_foreground_with(&red, &candidates, TieBreak::Last);
+///
+/// assert!(core::ptr::eq(last.unwrap(), &candidates[1]));
+/// ```
+pub fn pick_foreground_with<'a, T, B, C>(
+    background: &B,
+    candidates: &'a [C],
+    tie_break: TieBreak,
+) -> Option<&'a C>
+where
+    T: LuminanceValue,
+    T::Channel: Float,
+    T::Weighted: Float,
+    B: Luminance<T> + ?Sized,
+    C: Luminance<T>,
+{
+    let mut best: Option<(&C, T::Weighted)> = None;
+    for candidate in candidates {
+        let ratio = background.contrast_ratio(candidate);
+        let is_better = match best {
+            None => true,
+            Some((_, best_ratio)) if ratio != best_ratio => ratio > best_ratio,
+            Some((best, _)) => match tie_break {
+                TieBreak::First => false,
+                TieBreak::Last => true,
+                TieBreak::Darkest => {
+                    candidate.wcag_relative_luminance() < best.wcag_relative_luminance()
+                }
+                TieBreak::Lightest => {
+                    candidate.wcag_relative_luminance() > best.wcag_relative_luminance()
+                }
+            },
+        };
+        if is_better {
+            best = Some((candidate, ratio));
+        }
+    }
+    best.map(|(candidate, _)| candidate)
+}
+
+/// An error from [`ensure_contrast`] when neither black nor white text has the target
+/// contrast ratio with the background.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnreachableContrast;
+
+impl fmt::Display for UnreachableContrast {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the target contrast ratio can't be reached on the background"
+        )
+    }
+}
+
+impl std::error::Error for UnreachableContrast {}
+
+/// Darkens or lightens a foreground color as little as possible until it has at least
+/// a target [contrast ratio](Luminance::contrast_ratio) with a background color.
+///
+/// Darkening scales the linear channels toward black, and lightening mixes them with
+/// white, so the hue stays the same. The color is first adjusted away from the
+/// background, and only crosses over it if that isn't enough. The result is encoded
+/// with [`Srgb`], and is the unchanged foreground if it already has the target ratio.
+///
+/// ```
+/// use relative_luminance::contrast::{self, ContrastRatio};
+/// use relative_luminance::Rgb;
+///
+/// let gray: Rgb<f64> = Rgb::new(0.6, 0.6, 0.6);
+/// let white: Rgb<f64> = Rgb::new(1.0, 1.0, 1.0);
+/// let adjusted = contrast::ensure_contrast(&gray, &white, 4.5).unwrap();
+///
+/// assert!(adjusted.r < 0.6);
+/// assert!(ContrastRatio::between(&adjusted, &white).passes_aa());
+/// assert!(contrast::ensure_contrast(&gray, &gray, 21.5).is_err());
+/// ```
+pub fn ensure_contrast<F, C, B>(
+    foreground: &C,
+    background: &B,
+    target: F,
+) -> Result<Rgb<F>, UnreachableContrast>
+where
+    F: Float + LuminanceValue<Channel = F, Weighted = F>,
+    C: Luminance<F> + ?Sized,
+    B: Luminance<F> + ?Sized,
+{
+    let f = F::from_f64;
+    let offset = f(0.05);
+    // Aim slightly past the target, so rounding doesn't leave the ratio just below it.
+    let target = target * f(1.0 + 1e-9);
+    let linear = foreground.linear_rgb();
+    let current = linear.relative_luminance();
+    let background = background.wcag_relative_luminance();
+    if ratio_from_luminance(current, background) >= target {
+        return Ok(linear.encode(&Srgb));
+    }
+
+    let darker = (background + offset) / target - offset;
+    let lighter = target * (background + offset) - offset;
+    let darken = || (darker >= f(0.0)).then(|| foreground.darken_to_luminance(darker));
+    let lighten = || (lighter <= f(1.0)).then(|| foreground.lighten_to_luminance(lighter));
+    let adjusted = if current >= background {
+        lighten().or_else(darken)
+    } else {
+        darken().or_else(lighten)
+    };
+    adjusted.ok_or(UnreachableContrast)
+}
+
+/// A minimum contrast of text on a background.
+///
+/// ```
+/// use relative_luminance::contrast::Requirement;
+/// use relative_luminance::Rgb;
+///
+/// let gray: Rgb<f64> = Rgb::new(0.5, 0.5, 0.5);
+/// let white: Rgb<f64> = Rgb::new(1.0, 1.0, 1.0);
+///
+/// assert!(Requirement::Wcag(3.0).is_met(&gray, &white));
+/// assert!(!Requirement::Apca(75.0).is_met(&gray, &white));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum Requirement<F> {
+    /// A minimum [WCAG contrast ratio](Luminance::contrast_ratio), like 4.5.
+    Wcag(F),
+    /// A minimum magnitude of the [APCA](apca::contrast) lightness contrast (Lc), like
+    /// 60.0, for either polarity.
+    Apca(F),
+}
+
+impl<F> Requirement<F>
+where
+    F: Float + LuminanceValue<Channel = F, Weighted = F>,
+{
+    /// Checks if text has the required contrast on a background.
+    pub fn is_met<Text, Background>(&self, text: &Text, background: &Background) -> bool
+    where
+        Text: Luminance<F> + ?Sized,
+        Background: Luminance<F> + ?Sized,
+    {
+        match *self {
+            Requirement::Wcag(ratio) => text.contrast_ratio(background) >= ratio,
+            Requirement::Apca(lc) => apca::contrast(text, background).abs() >= lc,
+        }
+    }
+}
+
+/// A color from [`fix_contrast`], and how much it was changed.
+#[derive(Clone, Copy, Debug)]
+pub struct ContrastFix<F: LuminanceValue<Channel = F>> {
+    /// The fixed color, encoded with [`Srgb`].
+    pub color: Rgb<F>,
+    /// The change of the [`Oklch`] lightness.
+    pub lightness_delta: F,
+    /// The change of the [`Oklch`] chroma, which is only reduced to stay in the sRGB
+    /// gamut.
+    pub chroma_delta: F,
+}
+
+/// Finds the closest color to a foreground color in [`Oklch`] that has at least a
+/// target [contrast ratio](Luminance::contrast_ratio) with a background color.
+///
+/// Only the lightness is searched, and the hue is kept. The chroma is kept too, unless
+/// colors with that lightness and chroma are outside of the sRGB gamut, where it's
+/// reduced as little as possible. Compared to [`ensure_contrast`], this keeps colors
+/// more saturated, since lightening in linear light mixes in white.
+///
+/// ```
+/// use relative_luminance::contrast::{self, ContrastRatio};
+/// use relative_luminance::Rgb;
+///
+/// let orange: Rgb<f64> = Rgb::new(1.0, 0.6, 0.2);
+/// let white: Rgb<f64> = Rgb::new(1.0, 1.0, 1.0);
+/// let fix = contrast::fix_contrast(&orange, &white, 4.5).unwrap();
+///
+/// assert!(fix.lightness_delta < 0.0);
+/// assert!(ContrastRatio::between(&fix.color, &white).passes_aa());
+/// ```
+pub fn fix_contrast<F, C, B>(
+    foreground: &C,
+    background: &B,
+    target: F,
+) -> Result<ContrastFix<F>, UnreachableContrast>
+where
+    F: Float + LuminanceValue<Channel = F, Weighted = F>,
+    C: Luminance<F> + ?Sized,
+    B: Luminance<F> + ?Sized,
+{
+    let f = F::from_f64;
+    // Aim slightly past the target, so rounding doesn't leave the ratio just below it.
+    let target = target * f(1.0 + 1e-9);
+    let original = Oklch::from_oklab(&Oklab::from_linear_rgb(&foreground.linear_rgb()));
+    let background = background.wcag_relative_luminance();
+    let color = |lightness| gamut_map_oklch(Oklch::new(lightness, original.c, original.h));
+    let luminance = |lightness| color(lightness).0.relative_luminance();
+    let passes = |lightness| ratio_from_luminance(luminance(lightness), background) >= target;
+
+    let start = clamp_unit(original.l);
+    let lightness = if passes(start) {
+        Some(start)
+    } else {
+        // Contrast falls toward the background's luminance from either side.
+        let darker = search(f(0.0), start, |l| luminance(l) < background && passes(l));
+        let lighter = search(f(1.0), start, |l| luminance(l) > background && passes(l));
+        match (darker, lighter) {
+            (Some(d), Some(l)) => Some(if start - d <= l - start { d } else { l }),
+            (d, l) => d.or(l),
+        }
+    };
+    let lightness = lightness.ok_or(UnreachableContrast)?;
+    let (linear, chroma) = color(lightness);
+    Ok(ContrastFix {
+        color: linear.encode(&Srgb),
+        lightness_delta: lightness - original.l,
+        chroma_delta: chroma - original.c,
+    })
+}
+
+/// Which of a foreground color and a background color is lighter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum Polarity {
+    /// The foreground is lighter, like in a dark theme. This is a negative
+    /// [APCA](apca::contrast) contrast.
+    LightOnDark,
+    /// The foreground is darker, like in a light theme. This is a positive APCA
+    /// contrast.
+    DarkOnLight,
+    /// Both colors have the same screen luminance.
+    Equal,
+}
+
+/// Gets the [`Polarity`] of a foreground color on a background color, from their
+/// [APCA screen luminances](apca::screen_luminance), so it agrees with the sign of
+/// [`apca::contrast`] whenever that isn't 0.
+///
+/// ```
+/// use relative_luminance::contrast::{self, Polarity};
+/// use relative_luminance::Rgb;
+///
+/// let yellow: Rgb<f32> = Rgb::new(1.0, 1.0, 0.0);
+/// let blue: Rgb<f32> = Rgb::new(0.0, 0.0, 1.0);
+///
+/// assert_eq!(contrast::polarity(&yellow, &blue), Polarity::LightOnDark);
+/// assert_eq!(contrast::polarity(&blue, &yellow), Polarity::DarkOnLight);
+/// assert_eq!(contrast::polarity(&blue, &blue), Polarity::Equal);
+/// ```
+pub fn polarity<T, Fg, Bg>(foreground: &Fg, background: &Bg) -> Polarity
+where
+    T: LuminanceValue,
+    T::Channel: Float,
+    Fg: Luminance<T> + ?Sized,
+    Bg: Luminance<T> + ?Sized,
+{
+    let foreground = apca::screen_luminance(foreground);
+    let background = apca::screen_luminance(background);
+    if foreground > background {
+        Polarity::LightOnDark
+    } else if foreground < background {
+        Polarity::DarkOnLight
+    } else {
+        Polarity::Equal
+    }
+}
+
+/// A [WCAG contrast ratio](Luminance::contrast_ratio), in the range [1.0, 21.0].
+///
+/// The checks use the minimum ratios of the WCAG 2.x
+/// [contrast (minimum)][minimum] and [contrast (enhanced)][enhanced] criteria, so code
+/// doesn't need to compare ratios to those numbers itself.
+///
+/// ```
+/// use relative_luminance::contrast::ContrastRatio;
+/// use relative_luminance::Rgb;
+///
+/// let white: Rgb<f32> = Rgb::new(1.0, 1.0, 1.0);
+/// let gray: Rgb<f32> = Rgb::new(0.46, 0.46, 0.46);
+/// let ratio = ContrastRatio::between(&white, &gray);
+///
+/// assert!(ratio.passes_aa());
+/// assert!(!ratio.passes_aaa());
+/// assert_eq!(ratio.to_string(), "4.58:1");
+/// ```
+///
+/// [minimum]: https://www.w3.org/TR/WCAG22/#contrast-minimum
+/// [enhanced]: https://www.w3.org/TR/WCAG22/#contrast-enhanced
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(transparent)
+)]
+pub struct ContrastRatio<F>(pub F);
+
+impl<F: Float> ContrastRatio<F> {
+    /// Gets the contrast ratio between two colors.
+    ///
+    /// See [`Luminance::contrast_ratio`].
+    pub fn between<T, A, B>(a: &A, b: &B) -> Self
+    where
+        T: LuminanceValue<Weighted = F>,
+        T::Channel: Float,
+        A: Luminance<T> + ?Sized,
+        B: Luminance<T> + ?Sized,
+    {
+        ContrastRatio(a.contrast_ratio(b))
+    }
+
+    /// Checks if the ratio is at least 4.5:1, the level AA minimum for normal text.
+    pub fn passes_aa(&self) -> bool {
+        self.0 >= F::from_f64(4.5)
+    }
+
+    /// Checks if the ratio is at least 7:1, the level AAA minimum for normal text.
+    pub fn passes_aaa(&self) -> bool {
+        self.0 >= F::from_f64(7.0)
+    }
+
+    /// Checks if the ratio is at least 3:1, the level AA minimum for large text.
+    pub fn passes_aa_large(&self) -> bool {
+        self.0 >= F::from_f64(3.0)
+    }
+
+    /// Checks if the ratio is at least 4.5:1, the level AAA minimum for large text.
+    pub fn passes_aaa_large(&self) -> bool {
+        self.0 >= F::from_f64(4.5)
+    }
+}
+
+/// Formats the ratio like `4.54:1`.
+///
+/// The ratio is truncated instead of rounded, so a ratio that fails a check is never
+/// shown as the minimum ratio, like 4.499:1 as 4.50:1. A precision can be given, like
+/// `{:.1}`, and defaults to 2.
+impl<F: Float + fmt::Display> fmt::Display for ContrastRatio<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        let scale = F::from_f64(10f64.powi(precision as i32));
+        let truncated = (self.0 * scale).floor() / scale;
+        write!(f, "{truncated:.precision$}:1")
+    }
+}
+
+/// Gets the [contrast ratio](Luminance::contrast_ratio) of every pair of colors in a
+/// palette, like the contrast grids of design tools.
+///
+/// ```
+/// use relative_luminance::{contrast, Rgb};
+///
+/// let palette: [Rgb<f64>; 3] = [
+///     Rgb::new(0.0, 0.0, 0.0),
+///     Rgb::new(0.5, 0.5, 0.5),
+///     Rgb::new(1.0, 1.0, 1.0),
+/// ];
+/// let grid = contrast::contrast_grid(&palette);
+///
+/// assert_eq!(grid.len(), 3);
+/// assert_eq!(grid.get(0, 1), grid.get(1, 0));
+/// assert_eq!(grid.best_pair().map(|(a, b, _)| (a, b)), Some((0, 2)));
+/// assert_eq!(grid.worst_pair().map(|(a, b, _)| (a, b)), Some((1, 2)));
+/// ```
+pub fn contrast_grid<T, C>(colors: &[C]) -> ContrastGrid<T::Weighted>
+where
+    T: LuminanceValue,
+    T::Channel: Float,
+    T::Weighted: Float,
+    C: Luminance<T>,
+{
+    let luminances: Vec<_> = colors
+        .iter()
+        .map(|color| color.wcag_relative_luminance())
+        .collect();
+    let ratios = luminances
+        .iter()
+        .flat_map(|&a| luminances.iter().map(move |&b| ratio_from_luminance(a, b)))
+        .collect();
+    ContrastGrid {
+        len: colors.len(),
+        ratios,
+    }
+}
+
+/// The contrast ratios of every pair of colors in a palette.
+///
+/// This is created by [`contrast_grid`]. Colors are referred to by their index in the
+/// palette, and a color has a ratio of 1.0 with itself.
+///
+/// With the `serde` feature, grids can be serialized for reports, but not deserialized,
+/// since the ratios must be a square matrix.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+pub struct ContrastGrid<F> {
+    len: usize,
+    /// The ratios in row-major order.
+    ratios: Vec<F>,
+}
+
+impl<F: Float> ContrastGrid<F> {
+    /// Gets the number of colors in the palette.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the palette has no colors.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets the contrast ratio of two colors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn get(&self, a: usize, b: usize) -> F {
+        assert!(a < self.len && b < self.len, "index out of bounds");
+        self.ratios[a * self.len + b]
+    }
+
+    /// Gets the rows of the grid, where each row has the ratios of one color with
+    /// every color.
+    pub fn rows(&self) -> impl Iterator<Item = &[F]> {
+        // A chunk size of 0 panics, and an empty palette has no rows anyway.
+        self.ratios.chunks(self.len.max(1))
+    }
+
+    /// Gets the indices and contrast ratio of the pair of different colors with the
+    /// highest contrast, or [`None`] if there are fewer than 2 colors.
+    ///
+    /// The first pair is chosen if several have the same ratio.
+    pub fn best_pair(&self) -> Option<(usize, usize, F)> {
+        self.find_pair(|ratio, best| ratio > best)
+    }
+
+    /// Gets the indices and contrast ratio of the pair of different colors with the
+    /// lowest contrast, or [`None`] if there are fewer than 2 colors.
+    ///
+    /// The first pair is chosen if several have the same ratio.
+    pub fn worst_pair(&self) -> Option<(usize, usize, F)> {
+        self.find_pair(|ratio, worst| ratio < worst)
+    }
+
+    /// Finds the pair whose ratio is preferred over every other pair's.
+    fn find_pair(&self, is_preferred: impl Fn(F, F) -> bool) -> Option<(usize, usize, F)> {
+        let pairs = (0..self.len).flat_map(|a| (a + 1..self.len).map(move |b| (a, b)));
+        pairs.fold(None, |found, (a, b)| {
+            let ratio = self.get(a, b);
+            match found {
+                Some((_, _, found_ratio)) if !is_preferred(ratio, found_ratio) => found,
+                _ => Some((a, b, ratio)),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rgb;
+
+    #[test]
+    fn test_michelson_matches_weber_for_equal_areas() {
+        // For a target on a background of the same luminance as the mean of both,
+        // Michelson contrast equals the Weber contrast of the lighter color.
+        let (max, min) = (0.6f64, 0.2f64);
+        let mean = (max + min) / 2.0;
+        let michelson = michelson_from_luminance(max, min);
+        let weber = weber_from_luminance(max, mean);
+        assert!((michelson - weber).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_contrast_ratio_thresholds() {
+        let ratio = ContrastRatio(4.499f64);
+        assert!(!ratio.passes_aa() && ratio.passes_aa_large());
+        assert_eq!(ratio.to_string(), "4.49:1");
+        assert_eq!(format!("{:.1}", ContrastRatio(21.0f32)), "21.0:1");
+        assert!(ContrastRatio(7.0f32).passes_aaa());
+    }
+
+    /// A color with an exact relative luminance, so contrast ratios can tie.
+    struct Exact(f64);
+
+    impl Luminance<f64> for Exact {
+        fn luminance_rgb(&self) -> Rgb<f64> {
+            Rgb::new(self.0, self.0, self.0)
+        }
+
+        fn wcag_relative_luminance(&self) -> f64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_pick_foreground_tie_breaks() {
+        // Both candidates have a contrast ratio of 2:1
+        let background = Exact(0.45);
+        let candidates = [Exact(0.95), Exact(0.2)];
+        let pick = |tie_break| {
+            pick_foreground_with(&background, &candidates, tie_break).map(|candidate| candidate.0)
+        };
+        assert_eq!(pick(TieBreak::First), Some(0.95));
+        assert_eq!(pick(TieBreak::Last), Some(0.2));
+        assert_eq!(pick(TieBreak::Darkest), Some(0.2));
+        assert_eq!(pick(TieBreak::Lightest), Some(0.95));
+        assert!(pick_foreground(&background, &[] as &[Exact]).is_none());
+    }
+
+    #[test]
+    fn test_contrast_grid_pairs() {
+        let grid = contrast_grid(&[Exact(0.45), Exact(0.2), Exact(0.95)]);
+        assert_eq!(grid.rows().collect::<Vec<_>>()[1], [2.0, 1.0, 4.0]);
+        assert_eq!(grid.best_pair(), Some((1, 2, 4.0)));
+        // Both pairs with the first color have a ratio of 2:1
+        assert_eq!(grid.worst_pair(), Some((0, 1, 2.0)));
+
+        let single = contrast_grid(&[Exact(0.5)]);
+        assert_eq!(single.get(0, 0), 1.0);
+        assert_eq!(single.best_pair(), None);
+        assert_eq!(contrast_grid::<f64, Exact>(&[]).rows().count(), 0);
+    }
+
+    #[test]
+    fn test_ensure_contrast_directions() {
+        let navy = Rgb::<f64>::new(0.1, 0.1, 0.4);
+        let gray = Rgb::<f64>::new(0.5, 0.5, 0.5);
+        // Lighter colors are lightened, and darker colors are darkened
+        let lightened = ensure_contrast(&gray, &navy, 4.5).unwrap();
+        assert!(lightened.r > 0.5 && lightened.b > 0.5);
+        let darkened = ensure_contrast(&navy, &gray, 4.5).unwrap();
+        assert!(darkened.b < 0.4);
+        // There's no lighter color with a 4.5:1 ratio on this gray, so the
+        // foreground becomes darker instead
+        let light_gray = Rgb::<f64>::new(0.55, 0.55, 0.55);
+        let crossed = ensure_contrast(&light_gray, &gray, 4.5).unwrap();
+        assert!(crossed.r < 0.5);
+        for adjusted in [lightened, darkened, crossed] {
+            let background = if adjusted.r > 0.5 { navy } else { gray };
+            let ratio = ContrastRatio::between(&adjusted, &background);
+            // The color is adjusted as little as possible
+            assert!(ratio.passes_aa() && ratio.0 < 4.5001, "{ratio}");
+        }
+    }
+
+    #[test]
+    fn test_fix_contrast_keeps_hue() {
+        let blue = Rgb::<f64>::new(0.2, 0.4, 0.9);
+        let black = Rgb::<f64>::new(0.0, 0.0, 0.0);
+        let fix = fix_contrast(&blue, &black, 7.0).unwrap();
+        assert!(fix.lightness_delta > 0.0);
+        assert!(ContrastRatio::between(&fix.color, &black).passes_aaa());
+        let hue = |rgb: &Rgb<f64>| Oklch::from_oklab(&Oklab::from_linear_rgb(&rgb.decode(&Srgb))).h;
+        assert!((hue(&fix.color) - hue(&blue)).abs() < 0.5);
+
+        let unchanged = fix_contrast(&black, &blue, 1.0).unwrap();
+        assert_eq!(unchanged.lightness_delta, 0.0);
+    }
+
+    #[test]
+    fn test_polarity_matches_apca_sign() {
+        let gray = Rgb::<f64>::new(0.5, 0.5, 0.5);
+        // Magenta has a lower encoded weighted sum than the green gray, but is lighter.
+        let pairs = [
+            (Rgb::<f64>::new(0.1, 0.2, 0.3), gray),
+            (Rgb::new(0.9, 0.8, 0.7), gray),
+            (Rgb::new(1.0, 0.0, 1.0), Rgb::new(0.25, 0.3, 0.25)),
+        ];
+        for (foreground, background) in pairs {
+            let lc = apca::contrast(&foreground, &background);
+            let expected = if lc > 0.0 {
+                Polarity::DarkOnLight
+            } else {
+                Polarity::LightOnDark
+            };
+            assert_eq!(polarity(&foreground, &background), expected);
+        }
+    }
+
+    #[test]
+    fn test_text_color_prefers_dark_on_ties() {
+        let background = Rgb::<f64>::new(0.2, 0.4, 0.6);
+        let dark = Rgb::<f64>::new(0.5, 0.5, 0.5);
+        let light = dark;
+        assert!(core::ptr::eq(
+            text_color_with(&background, &dark, &light),
+            &dark
+        ));
+    }
+
+    #[test]
+    fn test_weber_of_background_is_zero() {
+        let gray = Rgb::<f64>::new(0.5, 0.5, 0.5);
+        assert_eq!(weber(&gray, &gray), 0.0);
+    }
+}
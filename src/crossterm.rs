@@ -0,0 +1,58 @@
+use crate::{ansi, Luminance, Rgb};
+use ::crossterm::style::Color;
+
+/// Colors from the [`crossterm`](::crossterm) crate.
+///
+/// Named and ANSI colors are resolved with the default [`ansi`] palette. The
+/// terminal's own default color ([`Color::Reset`]) can't be known, so it's treated as
+/// black.
+///
+/// ```
+/// use crossterm::style::Color;
+/// use relative_luminance::Luminance;
+///
+/// assert_eq!(Color::Rgb { r: 0, g: 255, b: 0 }.relative_luminance(), 0.7152);
+/// assert_eq!(Color::White.relative_luminance(), 1.0);
+/// assert_eq!(Color::AnsiValue(15).relative_luminance(), 1.0);
+/// ```
+impl Luminance<f32> for Color {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        let rgb = match *self {
+            Color::Rgb { r, g, b } => Rgb::new(r, g, b),
+            Color::AnsiValue(index) => ansi::indexed(index),
+            Color::Reset | Color::Black => ansi::indexed(0),
+            Color::DarkRed => ansi::indexed(1),
+            Color::DarkGreen => ansi::indexed(2),
+            Color::DarkYellow => ansi::indexed(3),
+            Color::DarkBlue => ansi::indexed(4),
+            Color::DarkMagenta => ansi::indexed(5),
+            Color::DarkCyan => ansi::indexed(6),
+            Color::Grey => ansi::indexed(7),
+            Color::DarkGrey => ansi::indexed(8),
+            Color::Red => ansi::indexed(9),
+            Color::Green => ansi::indexed(10),
+            Color::Yellow => ansi::indexed(11),
+            Color::Blue => ansi::indexed(12),
+            Color::Magenta => ansi::indexed(13),
+            Color::Cyan => ansi::indexed(14),
+            Color::White => ansi::indexed(15),
+        };
+        rgb.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_match_indexed() {
+        let named = [Color::Black, Color::DarkBlue, Color::Grey, Color::Cyan];
+        for (color, index) in named.into_iter().zip([0, 4, 7, 14]) {
+            assert_eq!(
+                color.relative_luminance(),
+                Color::AnsiValue(index).relative_luminance()
+            );
+        }
+    }
+}
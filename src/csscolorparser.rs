@@ -0,0 +1,43 @@
+use crate::{Luminance, Rgb};
+use ::csscolorparser::{Color, ParseColorError};
+
+/// Colors from the [`csscolorparser`](::csscolorparser) crate, ignoring the alpha
+/// channel.
+impl Luminance<f32> for Color {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        Rgb::new(self.r, self.g, self.b)
+    }
+}
+
+/// Parses a CSS color string, like `"#663399"`, `"rgb(0 128 255)"`, or
+/// `"rebeccapurple"`, and gets its relative luminance.
+///
+/// The alpha channel is ignored. See [`csscolorparser::parse`](::csscolorparser::parse)
+/// for every supported format. To use the other [`Luminance`] methods, parse the color
+/// first.
+///
+/// ```
+/// use relative_luminance::{luminance_of_css, Luminance};
+///
+/// assert_eq!(luminance_of_css("#00ff00").unwrap(), 0.7152);
+/// assert_eq!(luminance_of_css("blue").unwrap(), 0.0722);
+/// assert!(luminance_of_css("not a color").is_err());
+///
+/// let gray = csscolorparser::parse("hsl(0deg 0% 50%)").unwrap();
+/// assert!((gray.wcag_relative_luminance() - 0.2140).abs() < 0.001);
+/// ```
+pub fn luminance_of_css(color: &str) -> Result<f32, ParseColorError> {
+    ::csscolorparser::parse(color).map(|color| color.relative_luminance())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_rgb() {
+        let rgb = Rgb::<u8>::new(0x66, 0x33, 0x99);
+        let luminance = luminance_of_css("rebeccapurple").unwrap();
+        assert!((luminance - rgb.relative_luminance()).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,347 @@
+use crate::{Float, Luminance, LuminanceValue, Rgb};
+
+/// Gets the larger of two values.
+fn max<F: Float>(a: F, b: F) -> F {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Gets the smaller of two values.
+fn min<F: Float>(a: F, b: F) -> F {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Wraps a hue in degrees to [0.0, 360.0).
+fn wrap_hue<F: Float>(hue: F) -> F {
+    let full_turn = F::from_f64(360.0);
+    hue - full_turn * (hue / full_turn).floor()
+}
+
+/// Gets the hue, in degrees, and the largest and smallest channels of an RGB color.
+fn hue_max_min<F>(rgb: &Rgb<F>) -> (F, F, F)
+where
+    F: Float + LuminanceValue<Channel = F>,
+{
+    let f = F::from_f64;
+    let Rgb { r, g, b } = *rgb;
+    let largest = max(max(r, g), b);
+    let smallest = min(min(r, g), b);
+    let chroma = largest - smallest;
+    let hue = if chroma <= f(0.0) {
+        f(0.0)
+    } else if largest == r {
+        (g - b) / chroma
+    } else if largest == g {
+        (b - r) / chroma + f(2.0)
+    } else {
+        (r - g) / chroma + f(4.0)
+    };
+    (wrap_hue(hue * f(60.0)), largest, smallest)
+}
+
+/// A color with a hue, saturation, and lightness, like the CSS `hsl()` function.
+///
+/// Note that HSL lightness isn't perceptual: fully saturated colors all have a
+/// lightness of 0.5, but very different luminances.
+///
+/// ```
+/// use relative_luminance::{Hsl, Luminance};
+///
+/// let yellow: Hsl<f32> = Hsl::new(60.0, 1.0, 0.5);
+/// let blue: Hsl<f32> = Hsl::new(240.0, 1.0, 0.5);
+///
+/// assert_eq!(yellow.l, blue.l);
+/// assert!(yellow.relative_luminance() > 0.9);
+/// assert!(blue.relative_luminance() < 0.1);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsl<F> {
+    /// The hue, in degrees.
+    pub h: F,
+    /// The saturation, in the range [0.0, 1.0].
+    pub s: F,
+    /// The lightness, in the range [0.0, 1.0].
+    pub l: F,
+}
+
+impl<F> Hsl<F>
+where
+    F: Float + LuminanceValue<Channel = F>,
+{
+    /// Creates a new `Hsl<F>`.
+    pub fn new(h: F, s: F, l: F) -> Self {
+        Hsl { h, s, l }
+    }
+
+    /// Converts an RGB color with channels in [0.0, 1.0] to HSL.
+    ///
+    /// ```
+    /// use relative_luminance::{Hsl, Rgb};
+    ///
+    /// let hsl = Hsl::from_rgb(&Rgb::<f32>::new(0.0, 0.5, 1.0));
+    ///
+    /// assert_eq!((hsl.h, hsl.s, hsl.l), (210.0, 1.0, 0.5));
+    /// ```
+    pub fn from_rgb(rgb: &Rgb<F>) -> Self {
+        let f = F::from_f64;
+        let (h, largest, smallest) = hue_max_min(rgb);
+        let l = (largest + smallest) / f(2.0);
+        let s = if l <= f(0.0) || l >= f(1.0) {
+            f(0.0)
+        } else {
+            (largest - l) / min(l, f(1.0) - l)
+        };
+        Hsl { h, s, l }
+    }
+
+    /// Converts the color to RGB, with the CSS algorithm.
+    pub fn to_rgb(&self) -> Rgb<F> {
+        let f = F::from_f64;
+        let hue = wrap_hue(self.h);
+        let a = self.s * min(self.l, f(1.0) - self.l);
+        let channel = |n: f64| {
+            let k = f(n) + hue / f(30.0);
+            let k = k - f(12.0) * (k / f(12.0)).floor();
+            self.l - a * max(f(-1.0), min(min(k - f(3.0), f(9.0) - k), f(1.0)))
+        };
+        Rgb::new(channel(0.0), channel(8.0), channel(4.0))
+    }
+}
+
+impl<F> Luminance<F> for Hsl<F>
+where
+    F: Float + LuminanceValue<Channel = F>,
+{
+    fn luminance_rgb(&self) -> Rgb<F> {
+        self.to_rgb()
+    }
+}
+
+/// A color with a hue, saturation, and value, also known as HSB.
+///
+/// ```
+/// use relative_luminance::{Hsv, Luminance};
+///
+/// let white: Hsv<f32> = Hsv::new(0.0, 0.0, 1.0);
+/// let green: Hsv<f32> = Hsv::new(120.0, 1.0, 1.0);
+///
+/// assert_eq!(white.relative_luminance(), 1.0);
+/// assert_eq!(green.relative_luminance(), 0.7152);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsv<F> {
+    /// The hue, in degrees.
+    pub h: F,
+    /// The saturation, in the range [0.0, 1.0].
+    pub s: F,
+    /// The value, or brightness, in the range [0.0, 1.0].
+    pub v: F,
+}
+
+impl<F> Hsv<F>
+where
+    F: Float + LuminanceValue<Channel = F>,
+{
+    /// Creates a new `Hsv<F>`.
+    pub fn new(h: F, s: F, v: F) -> Self {
+        Hsv { h, s, v }
+    }
+
+    /// Converts an RGB color with channels in [0.0, 1.0] to HSV.
+    ///
+    /// ```
+    /// use relative_luminance::{Hsv, Rgb};
+    ///
+    /// let hsv = Hsv::from_rgb(&Rgb::<f32>::new(0.0, 0.5, 1.0));
+    ///
+    /// assert_eq!((hsv.h, hsv.s, hsv.v), (210.0, 1.0, 1.0));
+    /// ```
+    pub fn from_rgb(rgb: &Rgb<F>) -> Self {
+        let f = F::from_f64;
+        let (h, largest, smallest) = hue_max_min(rgb);
+        let s = if largest <= f(0.0) {
+            f(0.0)
+        } else {
+            (largest - smallest) / largest
+        };
+        Hsv { h, s, v: largest }
+    }
+
+    /// Converts the color to RGB.
+    pub fn to_rgb(&self) -> Rgb<F> {
+        let f = F::from_f64;
+        let hue = wrap_hue(self.h);
+        let channel = |n: f64| {
+            let k = f(n) + hue / f(60.0);
+            let k = k - f(6.0) * (k / f(6.0)).floor();
+            self.v - self.v * self.s * max(f(0.0), min(min(k, f(4.0) - k), f(1.0)))
+        };
+        Rgb::new(channel(5.0), channel(3.0), channel(1.0))
+    }
+}
+
+impl<F> Luminance<F> for Hsv<F>
+where
+    F: Float + LuminanceValue<Channel = F>,
+{
+    fn luminance_rgb(&self) -> Rgb<F> {
+        self.to_rgb()
+    }
+}
+
+/// A color with a hue, whiteness, and blackness, like the CSS [`hwb()`][hwb] function.
+///
+/// ```
+/// use relative_luminance::{Hwb, Luminance};
+///
+/// let red: Hwb<f32> = Hwb::new(0.0, 0.0, 0.0);
+/// let gray: Hwb<f32> = Hwb::new(0.0, 0.6, 0.6);
+///
+/// assert_eq!(red.relative_luminance(), 0.2126);
+/// assert_eq!(gray.relative_luminance(), 0.5);
+/// ```
+///
+/// [hwb]: https://www.w3.org/TR/css-color-4/#the-hwb-notation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hwb<F> {
+    /// The hue, in degrees.
+    pub h: F,
+    /// The amount of white mixed in, in the range [0.0, 1.0].
+    pub w: F,
+    /// The amount of black mixed in, in the range [0.0, 1.0].
+    pub b: F,
+}
+
+impl<F> Hwb<F>
+where
+    F: Float + LuminanceValue<Channel = F>,
+{
+    /// Creates a new `Hwb<F>`.
+    pub fn new(h: F, w: F, b: F) -> Self {
+        Hwb { h, w, b }
+    }
+
+    /// Converts an RGB color with channels in [0.0, 1.0] to HWB.
+    ///
+    /// ```
+    /// use relative_luminance::{Hwb, Rgb};
+    ///
+    /// let hwb = Hwb::from_rgb(&Rgb::<f32>::new(0.25, 0.5, 0.75));
+    ///
+    /// assert_eq!((hwb.h, hwb.w, hwb.b), (210.0, 0.25, 0.25));
+    /// ```
+    pub fn from_rgb(rgb: &Rgb<F>) -> Self {
+        let f = F::from_f64;
+        let (h, largest, smallest) = hue_max_min(rgb);
+        Hwb {
+            h,
+            w: smallest,
+            b: f(1.0) - largest,
+        }
+    }
+
+    /// Converts the color to RGB, with the CSS algorithm.
+    ///
+    /// If the whiteness and blackness add up to 1.0 or more, they're scaled down to
+    /// add up to 1.0, which is a shade of gray.
+    pub fn to_rgb(&self) -> Rgb<F> {
+        let f = F::from_f64;
+        let total = self.w + self.b;
+        if total >= f(1.0) {
+            let gray = self.w / total;
+            return Rgb::new(gray, gray, gray);
+        }
+        let Rgb { r, g, b } = Hsl::new(self.h, f(1.0), f(0.5)).to_rgb();
+        let scale = f(1.0) - total;
+        Rgb::new(r * scale + self.w, g * scale + self.w, b * scale + self.w)
+    }
+}
+
+impl<F> Luminance<F> for Hwb<F>
+where
+    F: Float + LuminanceValue<Channel = F>,
+{
+    fn luminance_rgb(&self) -> Rgb<F> {
+        self.to_rgb()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks that converting colors to a model and back gives the same color.
+    fn assert_round_trips(round_trip: impl Fn(&Rgb<f64>) -> Rgb<f64>) {
+        for &(r, g, b) in &[
+            (0.2, 0.4, 0.6),
+            (1.0, 0.0, 0.0),
+            (0.5, 0.5, 0.5),
+            (0.9, 0.1, 0.8),
+        ] {
+            let round_trip = round_trip(&Rgb::new(r, g, b));
+            assert!((round_trip.r - r).abs() < 1e-9);
+            assert!((round_trip.g - g).abs() < 1e-9);
+            assert!((round_trip.b - b).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_hsl_round_trip() {
+        assert_round_trips(|rgb| Hsl::from_rgb(rgb).to_rgb());
+    }
+
+    #[test]
+    fn test_hsv_round_trip() {
+        assert_round_trips(|rgb| Hsv::from_rgb(rgb).to_rgb());
+    }
+
+    #[test]
+    fn test_hsv_black_and_gray() {
+        let black = Hsv::<f64>::new(120.0, 0.8, 0.0).to_rgb();
+        assert_eq!((black.r, black.g, black.b), (0.0, 0.0, 0.0));
+        assert_eq!(Hsv::from_rgb(&black).s, 0.0);
+        let gray = Hsv::<f64>::new(120.0, 0.0, 0.4).to_rgb();
+        assert_eq!((gray.r, gray.g, gray.b), (0.4, 0.4, 0.4));
+    }
+
+    #[test]
+    fn test_hsv_luminance() {
+        let blue = Hsv::<f64>::new(240.0, 1.0, 0.5);
+        let rgb = Rgb::<f64>::new(0.0, 0.0, 0.5);
+        assert!((blue.relative_luminance() - rgb.relative_luminance()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_hwb_round_trip() {
+        assert_round_trips(|rgb| Hwb::from_rgb(rgb).to_rgb());
+    }
+
+    #[test]
+    fn test_hwb_normalizes_whiteness_and_blackness() {
+        let gray = Hwb::<f64>::new(60.0, 0.5, 1.5).to_rgb();
+        assert_eq!((gray.r, gray.g, gray.b), (0.25, 0.25, 0.25));
+        let limit = Hwb::<f64>::new(60.0, 0.75, 0.25).to_rgb();
+        assert_eq!((limit.r, limit.g, limit.b), (0.75, 0.75, 0.75));
+    }
+
+    #[test]
+    fn test_hwb_luminance() {
+        let pink = Hwb::<f64>::new(0.0, 0.5, 0.0);
+        let rgb = Rgb::<f64>::new(1.0, 0.5, 0.5);
+        assert!((pink.relative_luminance() - rgb.relative_luminance()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_hue_wraps() {
+        let red = Hsl::<f64>::new(0.0, 1.0, 0.5).to_rgb();
+        let wrapped = Hsl::<f64>::new(-360.0, 1.0, 0.5).to_rgb();
+        assert_eq!((red.r, red.g, red.b), (wrapped.r, wrapped.g, wrapped.b));
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{Luminance, PremultipliedRgba, Rgb};
+use ::ecolor::Color32;
+
+impl From<Color32> for PremultipliedRgba<f32> {
+    fn from(color: Color32) -> Self {
+        let [r, g, b, a] = color.to_normalized_gamma_f32();
+        PremultipliedRgba::new(r, g, b, a)
+    }
+}
+
+/// Colors from the [`egui`](https://crates.io/crates/egui) crate.
+///
+/// `Color32` premultiplies its sRGB-encoded channels, so the channels are
+/// un-premultiplied like [`PremultipliedRgba`] before the luminance is calculated. To
+/// account for the background, convert the color to a [`PremultipliedRgba`] and use
+/// [`PremultipliedRgba::composited_luminance`].
+///
+/// ```
+/// use ecolor::Color32;
+/// use relative_luminance::{Luminance, PremultipliedRgba, Rgb};
+///
+/// let overlay = Color32::from_white_alpha(128);
+/// let black: Rgb<f32> = Rgb::new(0.0, 0.0, 0.0);
+///
+/// assert_eq!(overlay.relative_luminance(), 1.0);
+/// assert!((PremultipliedRgba::from(overlay).composited_luminance(&black) - 0.5).abs() < 0.01);
+/// ```
+impl Luminance<f32> for Color32 {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        PremultipliedRgba::from(*self).luminance_rgb()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unmultiplied() {
+        let opaque = Color32::from_rgb(0, 255, 0);
+        assert_eq!(opaque.relative_luminance(), 0.7152);
+        let translucent = Color32::from_rgba_unmultiplied(0, 255, 0, 51);
+        assert!((translucent.relative_luminance() - 0.7152).abs() < 1e-6);
+        assert_eq!(Color32::TRANSPARENT.relative_luminance(), 0.0);
+    }
+}
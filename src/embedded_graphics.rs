@@ -0,0 +1,40 @@
+use crate::{Luminance, Rgb};
+use ::embedded_graphics_core::pixelcolor::{
+    Bgr555, Bgr565, Bgr666, Bgr888, Rgb555, Rgb565, Rgb666, Rgb888, RgbColor,
+};
+
+macro_rules! impl_embedded_graphics {
+    ($($color:ident),*) => {
+        $(
+            /// Colors from the [`embedded-graphics`](::embedded_graphics_core) crate.
+            ///
+            /// The channels are normalized by their maximum values, so white is 1.0
+            /// with any bit depth.
+            impl Luminance<f32> for $color {
+                fn luminance_rgb(&self) -> Rgb<f32> {
+                    Rgb::new(
+                        f32::from(self.r()) / f32::from(Self::MAX_R),
+                        f32::from(self.g()) / f32::from(Self::MAX_G),
+                        f32::from(self.b()) / f32::from(Self::MAX_B),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_embedded_graphics!(Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_depths() {
+        assert_eq!(Rgb565::WHITE.relative_luminance(), 1.0);
+        assert_eq!(Rgb565::GREEN.relative_luminance(), 0.7152);
+        assert_eq!(Bgr888::BLUE.relative_luminance(), 0.0722);
+        let gray = Rgb888::new(119, 119, 119).wcag_relative_luminance();
+        assert!((gray - 0.1845).abs() < 0.0001);
+    }
+}
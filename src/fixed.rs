@@ -0,0 +1,48 @@
+use crate::LuminanceValue;
+use ::fixed::types::extra::{LeEqU16, LeEqU32, LeEqU64};
+use ::fixed::{FixedI16, FixedI32, FixedI64, FixedU16, FixedU32, FixedU64};
+
+macro_rules! impl_fixed {
+    ($($fixed:ident: $frac:ident),*) => {
+        $(
+            /// Fixed-point channels from the [`fixed`](::fixed) crate.
+            ///
+            /// The type needs enough integer bits to hold 1.0 for white to have a
+            /// luminance of 1.0, and enough fractional bits to hold the weights.
+            impl<Frac: $frac> LuminanceValue for $fixed<Frac> {
+                type Channel = $fixed<Frac>;
+                type Weight = $fixed<Frac>;
+                type Weighted = $fixed<Frac>;
+                const RED_WEIGHT: $fixed<Frac> = $fixed::lit("0.2126");
+                const GREEN_WEIGHT: $fixed<Frac> = $fixed::lit("0.7152");
+                const BLUE_WEIGHT: $fixed<Frac> = $fixed::lit("0.0722");
+            }
+        )*
+    };
+}
+
+impl_fixed!(
+    FixedI16: LeEqU16,
+    FixedI32: LeEqU32,
+    FixedI64: LeEqU64,
+    FixedU16: LeEqU16,
+    FixedU32: LeEqU32,
+    FixedU64: LeEqU64
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::Rgb;
+    use ::fixed::types::{I16F16, U0F16};
+
+    #[test]
+    fn test_fixed_point() {
+        let white = Rgb::<I16F16>::new(I16F16::ONE, I16F16::ONE, I16F16::ONE);
+        let green = Rgb::<I16F16>::new(I16F16::ZERO, I16F16::ONE, I16F16::ZERO);
+        assert!((white.relative_luminance() - I16F16::ONE).abs() <= I16F16::DELTA * 2);
+        assert_eq!(green.relative_luminance(), I16F16::lit("0.7152"));
+
+        let gray = Rgb::<U0F16>::new(U0F16::lit("0.5"), U0F16::lit("0.5"), U0F16::lit("0.5"));
+        assert!(gray.relative_luminance().abs_diff(U0F16::lit("0.5")) <= U0F16::DELTA * 2);
+    }
+}
@@ -0,0 +1,98 @@
+use core::ops::{Add, Div, Mul, Sub};
+
+/// Floating-point operations needed for calculations that go beyond a weighted sum,
+/// like decoding gamma-encoded channels.
+///
+/// This is implemented for [`f32`] and [`f64`], for the half-precision types of the
+/// [`half`](https://crates.io/crates/half) crate with the `half` feature, for the
+/// `BigFloat` of the [`num-bigfloat`](https://crates.io/crates/num-bigfloat) crate with
+/// the `num-bigfloat` feature, and for any
+/// [`num-traits`](https://crates.io/crates/num-traits) float wrapped in `NumFloat` with
+/// the `num-traits` feature.
+pub trait Float:
+    Copy
+    + PartialOrd
+    + Add<Self, Output = Self>
+    + Sub<Self, Output = Self>
+    + Mul<Self, Output = Self>
+    + Div<Self, Output = Self>
+{
+    /// Converts an `f64` constant to this type.
+    ///
+    /// ```
+    /// # use relative_luminance::Float;
+    /// assert_eq!(f32::from_f64(0.5), 0.5f32);
+    /// ```
+    fn from_f64(value: f64) -> Self;
+    /// Raises `self` to a floating-point power.
+    fn powf(self, n: Self) -> Self;
+    /// Gets the absolute value of `self`.
+    fn abs(self) -> Self;
+    /// Gets `e^(self)`.
+    fn exp(self) -> Self;
+    /// Gets the natural logarithm of `self`.
+    fn ln(self) -> Self;
+    /// Gets the largest integer less than or equal to `self`.
+    fn floor(self) -> Self;
+    /// Gets the sine of `self`, in radians.
+    fn sin(self) -> Self;
+    /// Gets the cosine of `self`, in radians.
+    fn cos(self) -> Self;
+    /// Gets the four-quadrant arctangent of `self` (y) and `other` (x), in radians.
+    fn atan2(self, other: Self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl Float for $t {
+                #[inline]
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+
+                #[inline]
+                fn powf(self, n: Self) -> Self {
+                    <$t>::powf(self, n)
+                }
+
+                #[inline]
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                #[inline]
+                fn exp(self) -> Self {
+                    <$t>::exp(self)
+                }
+
+                #[inline]
+                fn ln(self) -> Self {
+                    <$t>::ln(self)
+                }
+
+                #[inline]
+                fn floor(self) -> Self {
+                    <$t>::floor(self)
+                }
+
+                #[inline]
+                fn sin(self) -> Self {
+                    <$t>::sin(self)
+                }
+
+                #[inline]
+                fn cos(self) -> Self {
+                    <$t>::cos(self)
+                }
+
+                #[inline]
+                fn atan2(self, other: Self) -> Self {
+                    <$t>::atan2(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
@@ -0,0 +1,101 @@
+//! Luminance of [`glam`](::glam) vectors with linear RGB channels.
+//!
+//! The [`Luminance`] implementations treat `x`, `y`, and `z` as linear red, green,
+//! and blue. [`composited_luminance`] accounts for the alpha channel of a [`Vec4`].
+//!
+//! ```
+//! use glam::{Vec3, Vec4};
+//! use relative_luminance::glam::composited_luminance;
+//! use relative_luminance::Luminance;
+//!
+//! let overlay = Vec4::new(1.0, 1.0, 1.0, 0.25);
+//!
+//! assert_eq!(overlay.relative_luminance(), 1.0);
+//! assert_eq!(composited_luminance(overlay, Vec3::ZERO), 0.25);
+//! ```
+use crate::{Luminance, Rgb};
+use ::glam::{Vec3, Vec3A, Vec4};
+
+macro_rules! impl_glam {
+    ($($vector:ident),*) => {
+        $(
+            /// Vectors from the [`glam`](::glam) crate, with linear RGB channels in `x`,
+            /// `y`, and `z`.
+            ///
+            /// The channels are already linear, so they aren't decoded again by
+            /// [`Luminance::linear_rgb`].
+            impl Luminance<f32> for $vector {
+                fn luminance_rgb(&self) -> Rgb<f32> {
+                    Rgb::new(self.x, self.y, self.z)
+                }
+
+                fn linear_rgb(&self) -> Rgb<f32> {
+                    self.luminance_rgb()
+                }
+            }
+        )*
+    };
+}
+
+impl_glam!(Vec3, Vec3A);
+
+/// Vectors from the [`glam`](::glam) crate, with linear RGB channels in `x`, `y`, and
+/// `z`, and a straight alpha channel in `w`.
+///
+/// Like with [`Rgba`](crate::Rgba), the alpha channel is ignored. Use
+/// [`composited_luminance`] to account for the background.
+impl Luminance<f32> for Vec4 {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        Rgb::new(self.x, self.y, self.z)
+    }
+
+    fn linear_rgb(&self) -> Rgb<f32> {
+        self.luminance_rgb()
+    }
+}
+
+/// Gets the relative luminance of a color with a straight alpha channel blended over
+/// an opaque background.
+///
+/// Both colors are linear, so unlike
+/// [`Rgba::composite_over`](crate::Rgba::composite_over), this blends in linear light,
+/// like GPU blending into a linear framebuffer.
+///
+/// ```
+/// use glam::{Vec3, Vec4};
+/// use relative_luminance::glam::composited_luminance;
+///
+/// let red = Vec4::new(1.0, 0.0, 0.0, 0.5);
+///
+/// let luminance = composited_luminance(red, Vec3::ONE);
+///
+/// assert!((luminance - (0.5 * 0.2126 + 0.5)).abs() < 1e-6);
+/// ```
+pub fn composited_luminance(color: Vec4, background: Vec3) -> f32 {
+    background
+        .lerp(color.truncate(), color.w)
+        .relative_luminance()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_not_decoded() {
+        let gray = Vec3A::splat(0.5);
+        assert!((gray.wcag_relative_luminance() - 0.5).abs() < 1e-6);
+        assert_eq!(Vec3::Y.relative_luminance(), 0.7152);
+    }
+
+    #[test]
+    fn test_composited_extremes() {
+        let red = Vec4::new(1.0, 0.0, 0.0, 1.0);
+        let gray = Vec3::splat(0.5);
+        assert_eq!(composited_luminance(red, gray), red.relative_luminance());
+        assert_eq!(
+            composited_luminance(red.with_w(0.0), gray),
+            gray.relative_luminance()
+        );
+    }
+}
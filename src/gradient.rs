@@ -0,0 +1,153 @@
+//! Checking the contrast of text over gradients.
+//!
+//! Gradients are interpolated between the sRGB-encoded channels of their stops, like
+//! CSS gradients without an interpolation color space.
+//!
+//! ```
+//! use relative_luminance::gradient::{self, Stop};
+//! use relative_luminance::Rgb;
+//!
+//! let stops = [
+//!     Stop::new(0.0, Rgb::new(0.0, 0.0, 0.2)),
+//!     Stop::new(1.0, Rgb::new(0.6, 0.6, 0.9)),
+//! ];
+//! let white: Rgb<f64> = Rgb::new(1.0, 1.0, 1.0);
+//! let minimum = gradient::minimum_contrast(&stops, &white, 64).unwrap();
+//!
+//! assert_eq!(minimum.position, 1.0);
+//! assert!(!minimum.ratio.passes_aa());
+//! ```
+use crate::contrast::ContrastRatio;
+use crate::{Float, Luminance, LuminanceValue, Rgb};
+
+/// A color at a position of a gradient.
+#[derive(Clone, Copy, Debug)]
+pub struct Stop<F: LuminanceValue<Channel = F>> {
+    /// The position, usually in [0.0, 1.0].
+    pub position: F,
+    /// The color at the position.
+    pub color: Rgb<F>,
+}
+
+impl<F: LuminanceValue<Channel = F>> Stop<F> {
+    /// Creates a new `Stop<F>`.
+    pub fn new(position: F, color: Rgb<F>) -> Self {
+        Stop { position, color }
+    }
+}
+
+/// The lowest contrast of text over a gradient, from [`minimum_contrast`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct GradientContrast<F> {
+    /// The lowest contrast ratio.
+    pub ratio: ContrastRatio<F>,
+    /// The position of the lowest contrast ratio.
+    pub position: F,
+}
+
+/// Gets the color of a gradient at a position, or [`None`] if there are no stops.
+///
+/// The stops must be sorted by position. Positions before the first stop or after the
+/// last stop have the color of that stop.
+///
+/// ```
+/// use relative_luminance::gradient::{self, Stop};
+/// use relative_luminance::Rgb;
+///
+/// let stops = [
+///     Stop::new(0.0, Rgb::<f32>::new(0.0, 0.0, 0.0)),
+///     Stop::new(0.5, Rgb::new(1.0, 0.0, 0.0)),
+/// ];
+///
+/// assert_eq!(gradient::color_at(&stops, 0.25).unwrap().r, 0.5);
+/// assert_eq!(gradient::color_at(&stops, 0.75).unwrap().r, 1.0);
+/// ```
+pub fn color_at<F>(stops: &[Stop<F>], position: F) -> Option<Rgb<F>>
+where
+    F: Float + LuminanceValue<Channel = F>,
+{
+    let first = stops.first()?;
+    Some(interpolate(first, stops, position))
+}
+
+/// Gets the color of a gradient at a position, given its first stop.
+fn interpolate<F>(first: &Stop<F>, stops: &[Stop<F>], position: F) -> Rgb<F>
+where
+    F: Float + LuminanceValue<Channel = F>,
+{
+    if position <= first.position {
+        return first.color;
+    }
+    let Some(after) = stops.iter().position(|stop| stop.position > position) else {
+        return stops.last().unwrap_or(first).color;
+    };
+    let (start, end) = (stops[after - 1], stops[after]);
+    let t = (position - start.position) / (end.position - start.position);
+    let mix = |a: F, b: F| a + (b - a) * t;
+    Rgb::new(
+        mix(start.color.r, end.color.r),
+        mix(start.color.g, end.color.g),
+        mix(start.color.b, end.color.b),
+    )
+}
+
+/// Gets the lowest [contrast ratio](Luminance::contrast_ratio) of text over a gradient,
+/// or [`None`] if there are no stops.
+///
+/// The gradient is sampled at `samples` evenly spaced positions from its first stop to
+/// its last stop, and at every stop. The stops must be sorted by position. The first
+/// position is chosen if several have the same ratio.
+pub fn minimum_contrast<F, T>(
+    stops: &[Stop<F>],
+    text: &T,
+    samples: usize,
+) -> Option<GradientContrast<F>>
+where
+    F: Float + LuminanceValue<Channel = F, Weighted = F>,
+    T: Luminance<F> + ?Sized,
+{
+    let first_stop = stops.first()?;
+    let (first, last) = (first_stop.position, stops.last()?.position);
+    let steps = samples.saturating_sub(1).max(1);
+    let sampled = (0..samples).map(|i| {
+        let t = F::from_f64(i as f64 / steps as f64);
+        first + (last - first) * t
+    });
+    let positions = sampled.chain(stops.iter().map(|stop| stop.position));
+    positions.fold(None, |minimum: Option<GradientContrast<F>>, position| {
+        let color = interpolate(first_stop, stops, position);
+        let ratio = ContrastRatio::between(&color, text);
+        match minimum {
+            Some(minimum) if minimum.ratio <= ratio => Some(minimum),
+            _ => Some(GradientContrast { ratio, position }),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimum_at_middle_stop() {
+        // A light stop between dark stops, which sampling alone could miss
+        let black = Rgb::<f64>::new(0.0, 0.0, 0.0);
+        let stops = [
+            Stop::new(0.0, black),
+            Stop::new(0.37, Rgb::new(1.0, 1.0, 1.0)),
+            Stop::new(1.0, black),
+        ];
+        let minimum = minimum_contrast(&stops, &Rgb::new(1.0, 1.0, 1.0), 3).unwrap();
+        assert_eq!(minimum.position, 0.37);
+        assert_eq!(minimum.ratio, ContrastRatio(1.0));
+    }
+
+    #[test]
+    fn test_no_stops() {
+        let white = Rgb::<f32>::new(1.0, 1.0, 1.0);
+        let stops: [Stop<f32>; 0] = [];
+        assert!(color_at(&stops, 0.5).is_none());
+        assert!(minimum_contrast(&stops, &white, 10).is_none());
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{Float, LuminanceValue};
+use ::half::{bf16, f16};
+
+macro_rules! impl_half {
+    ($($t:ident),*) => {
+        $(
+            /// Half-precision channels from the [`half`](::half) crate.
+            impl LuminanceValue for $t {
+                type Channel = $t;
+                type Weight = $t;
+                type Weighted = $t;
+                const RED_WEIGHT: $t = $t::from_f32_const(0.2126);
+                const GREEN_WEIGHT: $t = $t::from_f32_const(0.7152);
+                const BLUE_WEIGHT: $t = $t::from_f32_const(0.0722);
+            }
+
+            /// The operations are done in `f32`, and rounded back to half precision.
+            impl Float for $t {
+                #[inline]
+                fn from_f64(value: f64) -> Self {
+                    $t::from_f64(value)
+                }
+
+                #[inline]
+                fn powf(self, n: Self) -> Self {
+                    $t::from_f32(self.to_f32().powf(n.to_f32()))
+                }
+
+                #[inline]
+                fn abs(self) -> Self {
+                    $t::from_f32(self.to_f32().abs())
+                }
+
+                #[inline]
+                fn exp(self) -> Self {
+                    $t::from_f32(self.to_f32().exp())
+                }
+
+                #[inline]
+                fn ln(self) -> Self {
+                    $t::from_f32(self.to_f32().ln())
+                }
+
+                #[inline]
+                fn floor(self) -> Self {
+                    $t::from_f32(self.to_f32().floor())
+                }
+
+                #[inline]
+                fn sin(self) -> Self {
+                    $t::from_f32(self.to_f32().sin())
+                }
+
+                #[inline]
+                fn cos(self) -> Self {
+                    $t::from_f32(self.to_f32().cos())
+                }
+
+                #[inline]
+                fn atan2(self, other: Self) -> Self {
+                    $t::from_f32(self.to_f32().atan2(other.to_f32()))
+                }
+            }
+        )*
+    };
+}
+
+impl_half!(f16, bf16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Luminance, Rgb};
+
+    #[test]
+    fn test_half_matches_f32() {
+        let half = Rgb::<f16>::new(f16::from_f32(0.2), f16::from_f32(0.4), f16::from_f32(0.6));
+        let single = Rgb::<f32>::new(0.2, 0.4, 0.6);
+        let difference = half.wcag_relative_luminance().to_f32() - single.wcag_relative_luminance();
+        assert!(difference.abs() < 1e-3);
+
+        let white = Rgb::<bf16>::new(bf16::ONE, bf16::ONE, bf16::ONE);
+        assert!((white.relative_luminance().to_f32() - 1.0).abs() < 1e-2);
+    }
+}
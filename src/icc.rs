@@ -0,0 +1,191 @@
+//! Reading luminance weights from [ICC][icc] v2 and v4 profiles.
+//!
+//! The `rXYZ`, `gXYZ`, and `bXYZ` tags of a matrix-based RGB profile hold the
+//! colorants of the profile's primaries, relative to the D50 profile connection space.
+//! Their Y values are the luminance weights of the profile's color space.
+//!
+//! ```no_run
+//! use relative_luminance::icc;
+//! use relative_luminance::{Luminance, Rgb};
+//!
+//! let profile = std::fs::read("Display P3.icc").unwrap();
+//! let weights = icc::weights::<f32>(&profile).unwrap();
+//!
+//! let green: Rgb<f32> = Rgb::new(0.0, 1.0, 0.0);
+//! let luminance = green.relative_luminance_with(&weights);
+//! ```
+//!
+//! [icc]: https://www.color.org/specification/ICC.1-2022-05.pdf
+use crate::{Float, LuminanceValue, Weights};
+use core::fmt;
+
+/// The size of the profile header.
+const HEADER_SIZE: usize = 128;
+/// The size of an entry in the tag table.
+const TAG_ENTRY_SIZE: usize = 12;
+/// The size of an `XYZType` with a single XYZ number.
+const XYZ_TYPE_SIZE: usize = 20;
+
+/// An error from reading an ICC profile.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IccError {
+    /// The data ended before the structure that was being read.
+    UnexpectedEnd,
+    /// The data doesn't have the `acsp` profile file signature.
+    InvalidSignature,
+    /// The profile doesn't have a required tag, like `rXYZ`.
+    MissingTag([u8; 4]),
+    /// A tag doesn't have the expected `XYZ ` type.
+    InvalidTagType([u8; 4]),
+}
+
+impl fmt::Display for IccError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IccError::UnexpectedEnd => write!(f, "unexpected end of ICC profile"),
+            IccError::InvalidSignature => write!(f, "missing ICC profile signature"),
+            IccError::MissingTag(tag) => {
+                write!(f, "missing ICC tag {:?}", String::from_utf8_lossy(tag))
+            }
+            IccError::InvalidTagType(tag) => {
+                write!(
+                    f,
+                    "ICC tag {:?} is not an XYZ tag",
+                    String::from_utf8_lossy(tag)
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for IccError {}
+
+/// Reads the luminance weights from the `rXYZ`, `gXYZ`, and `bXYZ` tags of an ICC
+/// profile.
+pub fn weights<F>(profile: &[u8]) -> Result<Weights<F>, IccError>
+where
+    F: Float + LuminanceValue<Weight = F>,
+{
+    let [_, red, _] = colorant(profile, *b"rXYZ")?;
+    let [_, green, _] = colorant(profile, *b"gXYZ")?;
+    let [_, blue, _] = colorant(profile, *b"bXYZ")?;
+    Ok(Weights::new(
+        F::from_f64(red),
+        F::from_f64(green),
+        F::from_f64(blue),
+    ))
+}
+
+/// Reads the XYZ values of a colorant tag.
+fn colorant(profile: &[u8], signature: [u8; 4]) -> Result<[f64; 3], IccError> {
+    if profile.get(36..40).ok_or(IccError::UnexpectedEnd)? != b"acsp" {
+        return Err(IccError::InvalidSignature);
+    }
+    let tag_count = read_u32(profile, HEADER_SIZE)? as usize;
+    let tag = (0..tag_count)
+        .map(|i| HEADER_SIZE + 4 + i * TAG_ENTRY_SIZE)
+        .find_map(|entry| match profile.get(entry..entry + 4) {
+            Some(tag) if tag == signature => Some(Ok(entry)),
+            Some(_) => None,
+            None => Some(Err(IccError::UnexpectedEnd)),
+        })
+        .ok_or(IccError::MissingTag(signature))??;
+
+    let offset = read_u32(profile, tag + 4)? as usize;
+    let data = profile
+        .get(offset..offset + XYZ_TYPE_SIZE)
+        .ok_or(IccError::UnexpectedEnd)?;
+    if &data[..4] != b"XYZ " {
+        return Err(IccError::InvalidTagType(signature));
+    }
+    Ok([
+        read_s15_fixed16(data, 8)?,
+        read_s15_fixed16(data, 12)?,
+        read_s15_fixed16(data, 16)?,
+    ])
+}
+
+/// Reads a big-endian `u32`.
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, IccError> {
+    data.get(offset..offset + 4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u32::from_be_bytes)
+        .ok_or(IccError::UnexpectedEnd)
+}
+
+/// Reads a big-endian signed 15.16 fixed-point number.
+fn read_s15_fixed16(data: &[u8], offset: usize) -> Result<f64, IccError> {
+    read_u32(data, offset).map(|bits| f64::from(bits as i32) / 65536.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s15_fixed16(value: f64) -> [u8; 4] {
+        ((value * 65536.0).round() as i32).to_be_bytes()
+    }
+
+    /// Builds a minimal profile with the colorant tags of sRGB.
+    fn srgb_profile() -> Vec<u8> {
+        let colorants = [
+            (*b"rXYZ", [0.4360, 0.2225, 0.0139]),
+            (*b"gXYZ", [0.3851, 0.7169, 0.0971]),
+            (*b"bXYZ", [0.1431, 0.0606, 0.7141]),
+        ];
+        let mut profile = vec![0; HEADER_SIZE];
+        profile[36..40].copy_from_slice(b"acsp");
+        profile.extend_from_slice(&(colorants.len() as u32).to_be_bytes());
+        let data_start = profile.len() + colorants.len() * TAG_ENTRY_SIZE;
+        for (i, (signature, _)) in colorants.iter().enumerate() {
+            let offset = (data_start + i * XYZ_TYPE_SIZE) as u32;
+            profile.extend_from_slice(signature);
+            profile.extend_from_slice(&offset.to_be_bytes());
+            profile.extend_from_slice(&(XYZ_TYPE_SIZE as u32).to_be_bytes());
+        }
+        for (_, xyz) in colorants {
+            profile.extend_from_slice(b"XYZ \0\0\0\0");
+            for value in xyz {
+                profile.extend_from_slice(&s15_fixed16(value));
+            }
+        }
+        profile
+    }
+
+    #[test]
+    fn test_reads_weights() {
+        let weights = weights::<f64>(&srgb_profile()).unwrap();
+        assert!((weights.r - 0.2225).abs() < 1e-4);
+        assert!((weights.g - 0.7169).abs() < 1e-4);
+        assert!((weights.b - 0.0606).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_errors() {
+        let profile = srgb_profile();
+        assert_eq!(weights::<f32>(&profile[..20]), Err(IccError::UnexpectedEnd));
+        assert_eq!(
+            weights::<f32>(&profile[..100]),
+            Err(IccError::UnexpectedEnd)
+        );
+
+        let mut unsigned = profile.clone();
+        unsigned[36..40].copy_from_slice(b"\0\0\0\0");
+        assert_eq!(weights::<f32>(&unsigned), Err(IccError::InvalidSignature));
+
+        let mut missing = profile.clone();
+        missing[HEADER_SIZE + 4..HEADER_SIZE + 8].copy_from_slice(b"wtpt");
+        assert_eq!(
+            weights::<f32>(&missing),
+            Err(IccError::MissingTag(*b"rXYZ"))
+        );
+
+        let mut invalid = profile;
+        let last = invalid.len() - XYZ_TYPE_SIZE;
+        invalid[last..last + 4].copy_from_slice(b"curv");
+        assert_eq!(
+            weights::<f32>(&invalid),
+            Err(IccError::InvalidTagType(*b"bXYZ"))
+        );
+    }
+}
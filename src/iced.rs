@@ -0,0 +1,31 @@
+use crate::{Luminance, Rgb};
+use ::iced_core::Color;
+
+/// sRGB-encoded colors from the [`iced`](https://crates.io/crates/iced) crate,
+/// ignoring the alpha channel.
+///
+/// ```
+/// use iced_core::Color;
+/// use relative_luminance::Luminance;
+///
+/// assert_eq!(Color::WHITE.relative_luminance(), 1.0);
+/// assert!(Color::from_rgb8(0x33, 0x33, 0x33).wcag_relative_luminance() < 0.05);
+/// ```
+impl Luminance<f32> for Color {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        Rgb::new(self.r, self.g, self.b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_linear() {
+        let color = Color::from_rgb(0.2, 0.5, 0.8);
+        let [r, g, b, _] = color.into_linear();
+        let linear = Rgb::<f32>::new(r, g, b).relative_luminance();
+        assert!((color.wcag_relative_luminance() - linear).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,97 @@
+//! Interop with the [`image`](::image) crate.
+//!
+//! [`Luminance`] is implemented for the [`Rgb`](::image::Rgb) and
+//! [`Rgba`](::image::Rgba) pixels with `u8`, `u16`, and `f32` channels, and
+//! [`luminance_map`] converts a whole image.
+//!
+//! ```
+//! use image::Rgb;
+//! use relative_luminance::Luminance;
+//!
+//! assert_eq!(Rgb([0u8, 255, 0]).relative_luminance(), 0.7152);
+//! ```
+use crate::{Luminance, Rgb};
+use ::image::{DynamicImage, GrayImage, ImageBuffer, Luma};
+
+macro_rules! impl_image {
+    ($($t:ty),*) => {
+        $(
+            /// Pixels from the [`image`](::image) crate.
+            impl Luminance<$t> for ::image::Rgb<$t> {
+                fn luminance_rgb(&self) -> Rgb<$t> {
+                    let [r, g, b] = self.0;
+                    Rgb::new(r, g, b)
+                }
+            }
+
+            /// Pixels from the [`image`](::image) crate, ignoring the alpha channel.
+            impl Luminance<$t> for ::image::Rgba<$t> {
+                fn luminance_rgb(&self) -> Rgb<$t> {
+                    let [r, g, b, _] = self.0;
+                    Rgb::new(r, g, b)
+                }
+            }
+        )*
+    };
+}
+
+impl_image!(u8, u16, f32);
+
+/// Gets a grayscale image of the relative luminance of every pixel.
+///
+/// The luminances are scaled to [0, 255], which is useful for viewing them or using
+/// them as a mask. Alpha channels are ignored. See [`luminance_map_f32`] to keep the
+/// full precision.
+///
+/// ```
+/// use image::{DynamicImage, Rgb, RgbImage};
+/// use relative_luminance::image::luminance_map;
+///
+/// let image = RgbImage::from_pixel(2, 2, Rgb([0, 255, 0]));
+/// let map = luminance_map(&DynamicImage::ImageRgb8(image));
+///
+/// assert_eq!(map.dimensions(), (2, 2));
+/// assert_eq!(map.get_pixel(0, 0).0, [182]);
+/// ```
+pub fn luminance_map(image: &DynamicImage) -> GrayImage {
+    let map = luminance_map_f32(image);
+    ImageBuffer::from_fn(map.width(), map.height(), |x, y| {
+        let [luminance] = map.get_pixel(x, y).0;
+        Luma([(luminance.clamp(0.0, 1.0) * 255.0).round() as u8])
+    })
+}
+
+/// Gets an image of the relative luminance of every pixel, in the range [0.0, 1.0].
+///
+/// The channels of every pixel format are normalized to [0.0, 1.0] before they're
+/// weighted, so images with different bit depths give the same luminances.
+pub fn luminance_map_f32(image: &DynamicImage) -> ImageBuffer<Luma<f32>, Vec<f32>> {
+    let image = image.to_rgb32f();
+    ImageBuffer::from_fn(image.width(), image.height(), |x, y| {
+        Luma([image.get_pixel(x, y).relative_luminance()])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::image::{Rgb, Rgba};
+
+    #[test]
+    fn test_luminance_map_bit_depths() {
+        let narrow = ::image::RgbImage::from_pixel(1, 1, Rgb([255, 0, 0]));
+        let wide = ::image::ImageBuffer::from_pixel(1, 1, Rgb([65535u16, 0, 0]));
+        let narrow = luminance_map_f32(&DynamicImage::ImageRgb8(narrow));
+        let wide = luminance_map_f32(&DynamicImage::ImageRgb16(wide));
+        assert_eq!(narrow.get_pixel(0, 0), wide.get_pixel(0, 0));
+    }
+
+    #[test]
+    fn test_pixels() {
+        assert_eq!(Rgb([0u8, 255, 0]).relative_luminance(), 0.7152);
+        assert_eq!(Rgba([0u8, 0, 255, 0]).relative_luminance(), 0.0722);
+        assert_eq!(Rgb([65535u16, 0, 0]).relative_luminance(), 0.2126);
+        let white: Rgb<f32> = Rgb([1.0, 1.0, 1.0]);
+        assert!((white.wcag_relative_luminance() - 1.0).abs() < 1e-6);
+    }
+}
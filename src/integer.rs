@@ -0,0 +1,156 @@
+use crate::{LuminanceValue, Rgb};
+use core::ops::Mul;
+
+/// A luminance weight for integer channels, which normalizes the channel to
+/// [0.0, 1.0] before weighting it.
+///
+/// This lets [`Rgb`] be used with 8-bit and 16-bit channels directly, which are
+/// normalized by 255 and 65535.
+///
+/// ```
+/// use relative_luminance::{Luminance, Rgb};
+///
+/// let green: Rgb<u8> = Rgb { r: 0, g: 255, b: 0 };
+///
+/// assert_eq!(green.relative_luminance(), 0.7152);
+///
+/// let deep_green: Rgb<u16> = Rgb { r: 0, g: 65535, b: 0 };
+///
+/// assert_eq!(deep_green.relative_luminance(), 0.7152);
+/// ```
+///
+/// Calculations that need floating-point channels, like
+/// [`Luminance::wcag_relative_luminance`](crate::Luminance::wcag_relative_luminance),
+/// can be used after converting the channels.
+///
+/// ```
+/// use relative_luminance::{Luminance, Rgb};
+///
+/// let gray: Rgb<u8> = Rgb::new(119, 119, 119);
+///
+/// assert!((Rgb::<f64>::from(gray).wcag_relative_luminance() - 0.1845).abs() < 0.0001);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct IntegerWeight(pub f32);
+
+macro_rules! impl_integer {
+    ($integer:ty, $max:literal) => {
+        impl Mul<IntegerWeight> for $integer {
+            type Output = f32;
+
+            fn mul(self, weight: IntegerWeight) -> f32 {
+                f32::from(self) / $max * weight.0
+            }
+        }
+
+        impl LuminanceValue for $integer {
+            type Channel = $integer;
+            type Weight = IntegerWeight;
+            type Weighted = f32;
+            const RED_WEIGHT: IntegerWeight = IntegerWeight(f32::RED_WEIGHT);
+            const GREEN_WEIGHT: IntegerWeight = IntegerWeight(f32::GREEN_WEIGHT);
+            const BLUE_WEIGHT: IntegerWeight = IntegerWeight(f32::BLUE_WEIGHT);
+        }
+    };
+}
+
+impl_integer!(u8, 255.0);
+impl_integer!(u16, 65535.0);
+
+macro_rules! impl_from_integer {
+    ($integer:ty, $max:literal, $($float:ty),*) => {
+        $(
+            impl From<Rgb<$integer>> for Rgb<$float> {
+                /// Normalizes the channels to [0.0, 1.0].
+                fn from(rgb: Rgb<$integer>) -> Self {
+                    Rgb {
+                        r: <$float>::from(rgb.r) / $max,
+                        g: <$float>::from(rgb.g) / $max,
+                        b: <$float>::from(rgb.b) / $max,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, 255.0, f32, f64);
+impl_from_integer!(u16, 65535.0, f32, f64);
+
+/// Gets the approximate [BT.601](crate::standard::Bt601) luma of 8-bit channels with
+/// only integer math, as `(77 * r + 151 * g + 28 * b) >> 8`.
+///
+/// This is meant for hot loops on platforms without fast floating-point math. The
+/// result differs from the exact luma `0.299 * r + 0.587 * g + 0.114 * b` by less
+/// than 2.2, and is always in [0, 255]. Note that this is gamma-encoded luma, not the
+/// BT.709 weights of [`Luminance::relative_luminance`](crate::Luminance::relative_luminance).
+///
+/// ```
+/// use relative_luminance::fast_luma_u8;
+///
+/// assert_eq!(fast_luma_u8(0, 0, 0), 0);
+/// assert_eq!(fast_luma_u8(255, 255, 255), 255);
+/// assert_eq!(fast_luma_u8(0, 255, 0), 150);
+/// ```
+pub const fn fast_luma_u8(r: u8, g: u8, b: u8) -> u8 {
+    ((77 * r as u16 + 151 * g as u16 + 28 * b as u16) >> 8) as u8
+}
+
+/// Like [`fast_luma_u8`], but for 16-bit channels, as
+/// `(19595 * r + 38470 * g + 7471 * b) >> 16`.
+///
+/// The result differs from the exact luma by less than 1.4, and is always in
+/// [0, 65535].
+///
+/// ```
+/// use relative_luminance::fast_luma_u16;
+///
+/// assert_eq!(fast_luma_u16(65535, 65535, 65535), 65535);
+/// ```
+pub const fn fast_luma_u16(r: u16, g: u16, b: u16) -> u16 {
+    ((19595 * r as u32 + 38470 * g as u32 + 7471 * b as u32) >> 16) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_normalized_float() {
+        let integer = Rgb::<u8>::new(51, 102, 153);
+        let float = Rgb::<f32>::from(integer);
+        assert_eq!(integer.relative_luminance(), float.relative_luminance());
+        let integer = Rgb::<u16>::new(13107, 26214, 39321);
+        let float = Rgb::<f64>::from(integer);
+        assert!(
+            (f64::from(integer.relative_luminance()) - float.relative_luminance()).abs() < 1e-6
+        );
+    }
+
+    #[test]
+    fn test_fast_luma_error_bounds() {
+        for r in (0..=255).step_by(5) {
+            for g in (0..=255).step_by(5) {
+                for b in (0..=255).step_by(5) {
+                    let exact = 0.299 * f64::from(r) + 0.587 * f64::from(g) + 0.114 * f64::from(b);
+                    let fast = f64::from(fast_luma_u8(r, g, b));
+                    assert!((fast - exact).abs() < 2.2);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fast_luma_u16_error_bounds() {
+        // 65535 is a multiple of 1285, so both ends of the range are included
+        for r in (0..=u16::MAX).step_by(1285) {
+            for g in (0..=u16::MAX).step_by(1285) {
+                for b in (0..=u16::MAX).step_by(1285) {
+                    let exact = 0.299 * f64::from(r) + 0.587 * f64::from(g) + 0.114 * f64::from(b);
+                    let fast = f64::from(fast_luma_u16(r, g, b));
+                    assert!((fast - exact).abs() < 1.4);
+                }
+            }
+        }
+    }
+}
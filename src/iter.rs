@@ -0,0 +1,110 @@
+use crate::{Luminance, LuminanceValue};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+/// Adds [`relative_luminances`](LuminanceIteratorExt::relative_luminances) to every
+/// iterator.
+pub trait LuminanceIteratorExt: Iterator + Sized {
+    /// Maps every color to its [relative luminance](Luminance::relative_luminance).
+    ///
+    /// ```
+    /// use relative_luminance::{LuminanceIteratorExt, Rgb};
+    ///
+    /// let pixels: Vec<Rgb<f32>> = vec![Rgb::new(0.0, 0.0, 0.0), Rgb::new(1.0, 1.0, 1.0)];
+    /// let luminances: Vec<f32> = pixels.iter().copied().relative_luminances().collect();
+    ///
+    /// assert_eq!(luminances, [0.0, 1.0]);
+    /// ```
+    fn relative_luminances<T>(self) -> RelativeLuminances<Self, T>
+    where
+        T: LuminanceValue,
+        Self::Item: Luminance<T>,
+    {
+        RelativeLuminances {
+            iter: self,
+            value: PhantomData,
+        }
+    }
+}
+
+impl<I: Iterator> LuminanceIteratorExt for I {}
+
+/// An iterator over the relative luminances of colors.
+///
+/// This is created by [`LuminanceIteratorExt::relative_luminances`].
+#[derive(Clone, Debug)]
+pub struct RelativeLuminances<I, T> {
+    iter: I,
+    value: PhantomData<fn() -> T>,
+}
+
+impl<I, T> Iterator for RelativeLuminances<I, T>
+where
+    I: Iterator,
+    I::Item: Luminance<T>,
+    T: LuminanceValue,
+{
+    type Item = T::Weighted;
+
+    fn next(&mut self) -> Option<T::Weighted> {
+        self.iter.next().map(|color| color.relative_luminance())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> DoubleEndedIterator for RelativeLuminances<I, T>
+where
+    I: DoubleEndedIterator,
+    I::Item: Luminance<T>,
+    T: LuminanceValue,
+{
+    fn next_back(&mut self) -> Option<T::Weighted> {
+        self.iter
+            .next_back()
+            .map(|color| color.relative_luminance())
+    }
+}
+
+impl<I, T> ExactSizeIterator for RelativeLuminances<I, T>
+where
+    I: ExactSizeIterator,
+    I::Item: Luminance<T>,
+    T: LuminanceValue,
+{
+}
+
+impl<I, T> FusedIterator for RelativeLuminances<I, T>
+where
+    I: FusedIterator,
+    I::Item: Luminance<T>,
+    T: LuminanceValue,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Hsl, Rgb};
+
+    #[test]
+    fn test_matches_relative_luminance() {
+        let colors = [Hsl::<f64>::new(0.0, 1.0, 0.5), Hsl::new(240.0, 1.0, 0.5)];
+        let luminances = colors.into_iter().relative_luminances();
+        assert_eq!(luminances.len(), 2);
+        let expected: Vec<_> = colors.iter().map(|c| c.relative_luminance()).collect();
+        assert_eq!(
+            luminances.rev().collect::<Vec<_>>(),
+            [expected[1], expected[0]]
+        );
+        let integers = [Rgb::<u8>::new(255, 255, 255)]
+            .into_iter()
+            .relative_luminances();
+        assert_eq!(
+            integers.collect::<Vec<_>>(),
+            [Rgb::<u8>::new(255, 255, 255).relative_luminance()]
+        );
+    }
+}
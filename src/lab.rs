@@ -0,0 +1,228 @@
+use crate::colorimetry::{self, Chromaticity};
+use crate::lightness::{lstar_to_y, y_to_lstar};
+use crate::{polar, transfer, Float, Luminance, LuminanceValue, Rgb};
+
+/// Gets the D65 white point in any float type.
+pub(crate) fn d65<F: Float>() -> Chromaticity<F> {
+    Chromaticity::new(F::from_f64(0.3127), F::from_f64(0.3290))
+}
+
+/// A [CIELAB][cielab] color, with the lightness L\* in [0.0, 100.0].
+///
+/// The relative luminance (Y) only depends on L\*, and can be recovered exactly with
+/// [`Lab::y`]. The [`Luminance`] methods treat the color as relative to a D65 white
+/// point, and convert it to sRGB.
+///
+/// ```
+/// use relative_luminance::{Lab, Luminance};
+///
+/// let gray: Lab<f64> = Lab::new(50.0, 0.0, 0.0);
+///
+/// assert!((gray.y() - 0.1842).abs() < 0.0001);
+/// assert!((gray.wcag_relative_luminance() - gray.y()).abs() < 0.001);
+/// ```
+///
+/// [cielab]: https://en.wikipedia.org/wiki/CIELAB_color_space
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lab<F> {
+    /// The lightness, L\*.
+    pub l: F,
+    /// The green–red axis, a\*.
+    pub a: F,
+    /// The blue–yellow axis, b\*.
+    pub b: F,
+}
+
+impl<F: Float> Lab<F> {
+    /// Creates a new `Lab<F>`.
+    pub fn new(l: F, a: F, b: F) -> Self {
+        Lab { l, a, b }
+    }
+
+    /// Gets the relative luminance (Y) of the color, from its lightness.
+    ///
+    /// This doesn't depend on the white point.
+    pub fn y(&self) -> F {
+        lstar_to_y(self.l)
+    }
+
+    /// Converts the color to CIE XYZ, relative to a white point with a luminance of
+    /// 1.0.
+    ///
+    /// ```
+    /// use relative_luminance::colorimetry::Chromaticity;
+    /// use relative_luminance::Lab;
+    ///
+    /// let white: Lab<f64> = Lab::new(100.0, 0.0, 0.0);
+    /// let [x, y, z] = white.to_xyz(Chromaticity::<f64>::D50);
+    ///
+    /// assert!((x - 0.9643).abs() < 0.0001);
+    /// assert_eq!(y, 1.0);
+    /// assert!((z - 0.8251).abs() < 0.0001);
+    /// ```
+    pub fn to_xyz(&self, white: Chromaticity<F>) -> [F; 3] {
+        let f = F::from_f64;
+        let [white_x, _, white_z] = white.to_xyz();
+        // Converting each f(t) back to a lightness lets `lstar_to_y` invert it.
+        let fy = (self.l + f(16.0)) / f(116.0);
+        let fx = fy + self.a / f(500.0);
+        let fz = fy - self.b / f(200.0);
+        let invert = |t: F| lstar_to_y(f(116.0) * t - f(16.0));
+        [white_x * invert(fx), self.y(), white_z * invert(fz)]
+    }
+
+    /// Converts a color from CIE XYZ, relative to a white point with a luminance of
+    /// 1.0.
+    ///
+    /// ```
+    /// use relative_luminance::colorimetry::Chromaticity;
+    /// use relative_luminance::Lab;
+    ///
+    /// let d65 = Chromaticity::<f64>::D65;
+    /// let color = Lab::new(70.0, -20.0, 35.0);
+    /// let round_trip = Lab::from_xyz(color.to_xyz(d65), d65);
+    ///
+    /// assert!((round_trip.a - color.a).abs() < 1e-9);
+    /// assert!((round_trip.b - color.b).abs() < 1e-9);
+    /// ```
+    pub fn from_xyz(xyz: [F; 3], white: Chromaticity<F>) -> Self {
+        let f = F::from_f64;
+        let [x, y, z] = xyz;
+        let [white_x, _, white_z] = white.to_xyz();
+        // f(t) is a lightness scaled back to [4/29, 1.0], like in `to_xyz`.
+        let scale = |t: F| (y_to_lstar(t) + f(16.0)) / f(116.0);
+        let (fx, fy, fz) = (scale(x / white_x), scale(y), scale(z / white_z));
+        Lab {
+            l: y_to_lstar(y),
+            a: f(500.0) * (fx - fy),
+            b: f(200.0) * (fy - fz),
+        }
+    }
+}
+
+impl<F> Luminance<F> for Lab<F>
+where
+    F: Float + LuminanceValue<Channel = F>,
+{
+    /// Gets the sRGB-encoded channels of the color.
+    fn luminance_rgb(&self) -> Rgb<F> {
+        self.linear_rgb().encode(&transfer::Srgb)
+    }
+
+    fn linear_rgb(&self) -> Rgb<F> {
+        let [r, g, b] = colorimetry::xyz_to_srgb(self.to_xyz(d65()));
+        Rgb::new(r, g, b)
+    }
+}
+
+/// A CIELAB color in cylindrical form, with a lightness, chroma, and hue.
+///
+/// ```
+/// use relative_luminance::{Lch, Luminance};
+///
+/// let color: Lch<f64> = Lch::new(60.0, 40.0, 120.0);
+///
+/// assert_eq!(color.to_lab().y(), color.y());
+/// assert!((color.wcag_relative_luminance() - color.y()).abs() < 0.001);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lch<F> {
+    /// The lightness, L\*.
+    pub l: F,
+    /// The chroma, C\*.
+    pub c: F,
+    /// The hue, in degrees.
+    pub h: F,
+}
+
+impl<F: Float> Lch<F> {
+    /// Creates a new `Lch<F>`.
+    pub fn new(l: F, c: F, h: F) -> Self {
+        Lch { l, c, h }
+    }
+
+    /// Gets the relative luminance (Y) of the color, from its lightness.
+    pub fn y(&self) -> F {
+        lstar_to_y(self.l)
+    }
+
+    /// Converts the color to rectangular form.
+    pub fn to_lab(&self) -> Lab<F> {
+        let (a, b) = polar::from_polar(self.c, self.h);
+        Lab::new(self.l, a, b)
+    }
+
+    /// Converts a color from rectangular form.
+    ///
+    /// ```
+    /// use relative_luminance::{Lab, Lch};
+    ///
+    /// let lch = Lch::from_lab(&Lab::<f64>::new(50.0, 0.0, 20.0));
+    ///
+    /// assert!((lch.c - 20.0).abs() < 1e-9);
+    /// assert!((lch.h - 90.0).abs() < 1e-9);
+    /// ```
+    pub fn from_lab(lab: &Lab<F>) -> Self {
+        let (c, h) = polar::to_polar(lab.a, lab.b);
+        Lch { l: lab.l, c, h }
+    }
+}
+
+impl<F> Luminance<F> for Lch<F>
+where
+    F: Float + LuminanceValue<Channel = F>,
+{
+    /// Gets the sRGB-encoded channels of the color.
+    fn luminance_rgb(&self) -> Rgb<F> {
+        self.to_lab().luminance_rgb()
+    }
+
+    fn linear_rgb(&self) -> Rgb<F> {
+        self.to_lab().linear_rgb()
+    }
+}
+
+/// Converts linear sRGB channels to CIELAB, relative to a D65 white point.
+pub(crate) fn from_linear_srgb<F: Float>(linear: [F; 3]) -> Lab<F> {
+    Lab::from_xyz(colorimetry::srgb_to_xyz(linear), d65())
+}
+
+/// Maps the L\* of linear sRGB channels to 100 − L\*, keeping the hue and as much of
+/// the chroma as fits in the sRGB gamut.
+pub(crate) fn invert_lightness<F: Float>(linear: [F; 3]) -> [F; 3] {
+    let lch = Lch::from_lab(&from_linear_srgb(linear));
+    polar::gamut_map_lch(Lch::new(F::from_f64(100.0) - lch.l, lch.c, lch.h))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lab_matches_srgb() {
+        // sRGB red is about L* 53.24, a* 80.09, b* 67.20 with a D65 white point.
+        let red = Lab::<f64>::new(53.24, 80.09, 67.20).linear_rgb();
+        assert!((red.r - 1.0).abs() < 0.001);
+        assert!(red.g.abs() < 0.001);
+        assert!(red.b.abs() < 0.001);
+    }
+
+    #[test]
+    fn test_lch_round_trip() {
+        let lab = Lab::<f64>::new(40.0, -30.0, -10.0);
+        let round_trip = Lch::from_lab(&lab).to_lab();
+        assert!((round_trip.a - lab.a).abs() < 1e-9);
+        assert!((round_trip.b - lab.b).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_invert_lightness_keeps_hue() {
+        let lab = |rgb| Lch::from_lab(&from_linear_srgb(rgb));
+        let color = [0.05f64, 0.1, 0.2];
+        let (before, after) = (lab(color), lab(invert_lightness(color)));
+        assert!((before.l + after.l - 100.0).abs() < 1e-6);
+        assert!((before.h - after.h).abs() < 1e-3);
+        let black = invert_lightness([1.0f64, 1.0, 1.0]);
+        assert!(black.iter().all(|channel| channel.abs() < 1e-6));
+    }
+}
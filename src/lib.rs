@@ -50,6 +50,131 @@
 //!
 //! [relative-luminance]: https://en.wikipedia.org/wiki/Relative_luminance
 use core::ops::{Add, Mul};
+
+#[cfg(feature = "csscolorparser")]
+pub use self::csscolorparser::luminance_of_css;
+pub use ansi::ansi_index_luminance;
+#[cfg(feature = "num-bigfloat")]
+pub use bigfloat::BigFloatWeight;
+pub use cylindrical::{Hsl, Hsv, Hwb};
+pub use float::Float;
+pub use integer::{fast_luma_u16, fast_luma_u8, IntegerWeight};
+pub use iter::{LuminanceIteratorExt, RelativeLuminances};
+pub use lab::{Lab, Lch};
+#[cfg(feature = "num-traits")]
+pub use num::NumFloat;
+pub use oklab::{Oklab, Oklch};
+pub use parse::ParseColorError;
+pub use q16::Q16_16;
+pub use rgba::{PremultipliedRgba, Rgba};
+pub use standard::Standard;
+use transfer::TransferFunction;
+pub use weights::Weights;
+
+/// Builds a table of the relative luminances of 256 colors at compile time, from an
+/// expression of the index that gives the 8-bit `[r, g, b]` channels of the color.
+///
+/// This is a macro because `const fn`s can't call closures. Float arithmetic in
+/// constants needs Rust 1.82.
+macro_rules! luminance_table {
+    (|$index:ident| $channels:expr) => {{
+        let mut table = [0.0; 256];
+        let mut $index = 0;
+        while $index < table.len() {
+            let [r, g, b]: [u8; 3] = $channels;
+            table[$index] = r as f32 / 255.0 * <f32 as $crate::LuminanceValue>::RED_WEIGHT
+                + g as f32 / 255.0 * <f32 as $crate::LuminanceValue>::GREEN_WEIGHT
+                + b as f32 / 255.0 * <f32 as $crate::LuminanceValue>::BLUE_WEIGHT;
+            $index += 1;
+        }
+        table
+    }};
+}
+
+pub mod ansi;
+pub mod apca;
+#[cfg(feature = "bevy")]
+mod bevy;
+#[cfg(feature = "num-bigfloat")]
+mod bigfloat;
+pub mod buffer;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "cint")]
+mod cint;
+pub mod colorimetry;
+pub mod contrast;
+#[cfg(feature = "crossterm")]
+mod crossterm;
+#[cfg(feature = "csscolorparser")]
+mod csscolorparser;
+mod cylindrical;
+#[cfg(feature = "egui")]
+mod egui;
+#[cfg(feature = "embedded-graphics")]
+mod embedded_graphics;
+#[cfg(feature = "fixed")]
+mod fixed;
+mod float;
+#[cfg(feature = "glam")]
+pub mod glam;
+pub mod gradient;
+#[cfg(feature = "half")]
+mod half;
+#[cfg(feature = "icc")]
+pub mod icc;
+#[cfg(feature = "iced")]
+mod iced;
+#[cfg(feature = "image")]
+pub mod image;
+mod integer;
+mod iter;
+mod lab;
+pub mod lightness;
+#[cfg(feature = "lut")]
+pub mod lut;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "names")]
+pub mod names;
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+#[cfg(feature = "num-traits")]
+mod num;
+mod oklab;
+#[cfg(feature = "owo-colors")]
+mod owo_colors;
+pub mod packed;
+#[cfg(feature = "palette")]
+mod palette;
+mod parse;
+mod polar;
+pub mod policy;
+mod q16;
+#[cfg(feature = "ratatui")]
+mod ratatui;
+#[cfg(feature = "num-rational")]
+mod rational;
+#[cfg(feature = "rgb")]
+mod rgb;
+mod rgba;
+#[cfg(feature = "serde")]
+mod serde;
+pub mod solve;
+pub mod spectral;
+pub mod standard;
+pub mod suggest;
+pub mod temperature;
+#[cfg(feature = "termcolor")]
+mod termcolor;
+pub mod theme;
+pub mod transfer;
+pub mod video;
+pub mod wcag;
+mod weights;
+
 /// This trait is used to define numerical types that can be used to calculate relative
 /// luminance values.
 ///
@@ -107,7 +232,16 @@ fn relative_luminance<T: LuminanceValue>(
     g: T::Channel,
     b: T::Channel,
 ) -> T::Weighted {
-    (r * T::RED_WEIGHT) + (g * T::GREEN_WEIGHT) + (b * T::BLUE_WEIGHT)
+    relative_luminance_as::<T, standard::Bt709>(r, g, b)
+}
+
+/// Gets the relative luminance of RGB channels using the weights of a [`Standard`].
+fn relative_luminance_as<T: LuminanceValue, S: Standard<T> + ?Sized>(
+    r: T::Channel,
+    g: T::Channel,
+    b: T::Channel,
+) -> T::Weighted {
+    (r * S::RED_WEIGHT) + (g * S::GREEN_WEIGHT) + (b * S::BLUE_WEIGHT)
 }
 
 impl LuminanceValue for f32 {
@@ -139,6 +273,25 @@ impl LuminanceValue for f64 {
 /// };
 /// ```
 #[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        ::zerocopy::FromBytes,
+        ::zerocopy::IntoBytes,
+        ::zerocopy::Immutable,
+        ::zerocopy::KnownLayout
+    )
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize),
+    serde(bound(serialize = "T::Channel: ::serde::Serialize"))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize)
+)]
 pub struct Rgb<T: LuminanceValue> {
     pub r: T::Channel,
     pub g: T::Channel,
@@ -150,6 +303,59 @@ impl<T: LuminanceValue> Rgb<T> {
     pub fn new(r: T::Channel, g: T::Channel, b: T::Channel) -> Self {
         Rgb { r, g, b }
     }
+    /// Decodes the channels to linear light with a [`TransferFunction`].
+    ///
+    /// This can be combined with [`Luminance::relative_luminance_as`] for colors that
+    /// have both their own transfer function and their own weights.
+    ///
+    /// ```
+    /// use relative_luminance::standard::ProPhotoRgb;
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let encoded: Rgb<f32> = Rgb::new(0.5, 0.5, 0.5);
+    /// let luminance = encoded
+    ///     .decode(&ProPhotoRgb)
+    ///     .relative_luminance_as::<ProPhotoRgb>();
+    ///
+    /// assert!((luminance - 0.287).abs() < 0.001);
+    /// ```
+    ///
+    /// [`TransferFunction`]: transfer::TransferFunction
+    pub fn decode(&self, transfer: &impl TransferFunction) -> Self
+    where
+        T::Channel: Float,
+    {
+        Rgb {
+            r: transfer.decode(self.r),
+            g: transfer.decode(self.g),
+            b: transfer.decode(self.b),
+        }
+    }
+
+    /// Encodes linear channels with a [`TransferFunction`].
+    ///
+    /// ```
+    /// use relative_luminance::transfer::Srgb;
+    /// use relative_luminance::Rgb;
+    ///
+    /// let encoded: Rgb<f64> = Rgb::new(0.25, 0.5, 0.75);
+    /// let round_trip = encoded.decode(&Srgb).encode(&Srgb);
+    ///
+    /// assert!((round_trip.g - encoded.g).abs() < 1e-9);
+    /// ```
+    ///
+    /// [`TransferFunction`]: transfer::TransferFunction
+    pub fn encode(&self, transfer: &impl TransferFunction) -> Self
+    where
+        T::Channel: Float,
+    {
+        Rgb {
+            r: transfer.encode(self.r),
+            g: transfer.encode(self.g),
+            b: transfer.encode(self.b),
+        }
+    }
+
     /// Gets the relative luminance of the color.
     fn relative_luminance(&self) -> T::Weighted {
         relative_luminance::<T>(self.r, self.g, self.b)
@@ -190,6 +396,496 @@ pub trait Luminance<T: LuminanceValue> {
     fn relative_luminance(&self) -> T::Weighted {
         self.luminance_rgb().relative_luminance()
     }
+
+    /// Gets the relative luminance using the weights of a [`Standard`] instead of the
+    /// weights of `T`.
+    ///
+    /// ```
+    /// use relative_luminance::standard::Bt601;
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let red: Rgb<f32> = Rgb::new(1.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(red.relative_luminance_as::<Bt601>(), 0.299);
+    /// ```
+    fn relative_luminance_as<S: Standard<T> + ?Sized>(&self) -> T::Weighted {
+        let Rgb { r, g, b } = self.luminance_rgb();
+        relative_luminance_as::<T, S>(r, g, b)
+    }
+
+    /// Gets the relative luminance using weights that were chosen at runtime.
+    ///
+    /// ```
+    /// use relative_luminance::{Luminance, Rgb, Weights};
+    ///
+    /// let weights: Weights<f32> = Weights::new(0.25, 0.5, 0.25);
+    /// let red: Rgb<f32> = Rgb::new(1.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(red.relative_luminance_with(&weights), 0.25);
+    /// ```
+    fn relative_luminance_with(&self, weights: &Weights<T>) -> T::Weighted
+    where
+        T::Weight: Copy,
+    {
+        let Rgb { r, g, b } = self.luminance_rgb();
+        (r * weights.r) + (g * weights.g) + (b * weights.b)
+    }
+
+    /// Gets the linear-light channels of the color.
+    ///
+    /// By default, this treats the channels returned by [`Luminance::luminance_rgb`] as
+    /// gamma-encoded sRGB in the range [0.0, 1.0], and decodes them. This is used by
+    /// [`Luminance::wcag_relative_luminance`] and the other calculations that need
+    /// linear light.
+    ///
+    /// ```
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let gray: Rgb<f32> = Rgb::new(0.5, 0.5, 0.5);
+    ///
+    /// assert!((gray.linear_rgb().g - 0.214).abs() < 0.001);
+    /// ```
+    ///
+    /// If your channels are already linear, you should override this to return
+    /// [`Luminance::luminance_rgb`].
+    fn linear_rgb(&self) -> Rgb<T>
+    where
+        T::Channel: Float,
+    {
+        self.luminance_rgb().decode(&transfer::Srgb)
+    }
+
+    /// Gets the relative luminance as defined by [WCAG 2.x][wcag-luminance].
+    ///
+    /// Unlike [`Luminance::relative_luminance`], this weighs the channels returned by
+    /// [`Luminance::linear_rgb`], so gamma-encoded sRGB channels are decoded to linear
+    /// light before they are weighted.
+    ///
+    /// ```
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let gray: Rgb<f32> = Rgb::new(0.5, 0.5, 0.5);
+    ///
+    /// assert!((gray.wcag_relative_luminance() - 0.214).abs() < 0.001);
+    /// assert!(gray.wcag_relative_luminance() < gray.relative_luminance());
+    /// ```
+    ///
+    /// [wcag-luminance]: https://www.w3.org/TR/WCAG22/#dfn-relative-luminance
+    fn wcag_relative_luminance(&self) -> T::Weighted
+    where
+        T::Channel: Float,
+    {
+        self.linear_rgb().relative_luminance()
+    }
+
+    /// Gets the relative luminance after decoding the channels returned by
+    /// [`Luminance::luminance_rgb`] to linear light with a [`TransferFunction`].
+    ///
+    /// ```
+    /// use relative_luminance::transfer::Srgb;
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let gray: Rgb<f32> = Rgb::new(0.5, 0.5, 0.5);
+    ///
+    /// assert_eq!(gray.decoded_relative_luminance(&Srgb), gray.wcag_relative_luminance());
+    /// ```
+    ///
+    /// [`TransferFunction`]: transfer::TransferFunction
+    fn decoded_relative_luminance(&self, transfer: &impl TransferFunction) -> T::Weighted
+    where
+        T::Channel: Float,
+    {
+        self.luminance_rgb().decode(transfer).relative_luminance()
+    }
+
+    /// Gets the [CIE L\*][lightness] perceptual lightness of this color, in the range
+    /// [0.0, 100.0], from [`Luminance::wcag_relative_luminance`].
+    ///
+    /// Relative luminance is linear in the amount of light, but perceived lightness is
+    /// not. This is often a better measure of whether a color *looks* light or dark.
+    ///
+    /// ```
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let gray: Rgb<f64> = Rgb::new(0.5, 0.5, 0.5);
+    ///
+    /// // Only around 21% of the light of white, but around 53% as light
+    /// assert!((gray.wcag_relative_luminance() - 0.214).abs() < 0.001);
+    /// assert!((gray.relative_lightness() - 53.39).abs() < 0.01);
+    /// ```
+    ///
+    /// [lightness]: https://en.wikipedia.org/wiki/CIELAB_color_space#Perceptual_differences
+    fn relative_lightness(&self) -> T::Weighted
+    where
+        T::Channel: Float,
+        T::Weighted: Float,
+    {
+        lightness::y_to_lstar(self.wcag_relative_luminance())
+    }
+
+    /// Gets the [`Brightness`](lightness::Brightness) of this color, by classifying its
+    /// [`Luminance::relative_lightness`] into [`Bands`](lightness::Bands).
+    ///
+    /// ```
+    /// use relative_luminance::lightness::{Bands, Brightness};
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let gray: Rgb<f64> = Rgb::new(0.5, 0.5, 0.5);
+    /// let strict = Bands::new(30.0, 55.0, 75.0, 90.0);
+    ///
+    /// assert_eq!(gray.classify(&Bands::default()), Brightness::Mid);
+    /// assert_eq!(gray.classify(&strict), Brightness::Dark);
+    /// ```
+    fn classify(&self, bands: &lightness::Bands<T::Weighted>) -> lightness::Brightness
+    where
+        T::Channel: Float,
+        T::Weighted: Float,
+    {
+        bands.classify(self.relative_lightness())
+    }
+
+    /// Gets the scotopic luminance of this color, which is how bright it appears to rod
+    /// vision in very dim light.
+    ///
+    /// This uses the approximation of the CIE scotopic luminosity function from
+    /// [Larson et al.][larson] on the CIE XYZ values of [`Luminance::linear_rgb`], which
+    /// is assumed to have the sRGB primaries. The result is on the same scale as
+    /// photopic luminance, so white is about 2.57. Rods are more sensitive to blue and
+    /// less sensitive to red, so blues appear relatively brighter at night.
+    ///
+    /// ```
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let red: Rgb<f32> = Rgb::new(1.0, 0.0, 0.0);
+    /// let blue: Rgb<f32> = Rgb::new(0.0, 0.0, 1.0);
+    ///
+    /// assert!(red.wcag_relative_luminance() > blue.wcag_relative_luminance());
+    /// assert!(red.scotopic_luminance() < blue.scotopic_luminance());
+    /// ```
+    ///
+    /// [larson]: https://doi.org/10.1109/2945.646233
+    fn scotopic_luminance(&self) -> T::Channel
+    where
+        T::Channel: Float,
+    {
+        let f = <T::Channel as Float>::from_f64;
+        let Rgb { r, g, b } = self.linear_rgb();
+        let [x, y, z] = colorimetry::srgb_to_xyz([r, g, b]);
+        if x <= f(0.0) {
+            return f(0.0);
+        }
+        y * (f(1.33) * (f(1.0) + (y + z) / x) - f(1.68))
+    }
+
+    /// Gets the perceived brightness of this color with the [HSP][hsp] color model.
+    ///
+    /// This is the square root of the sum of the squared channels, weighted with
+    /// (0.299, 0.587, 0.114). Unlike relative luminance, it works on the channels as
+    /// they are, without decoding them to linear light. The result is in the range
+    /// [0.0, 1.0], and colors above 0.5 are commonly considered bright.
+    ///
+    /// ```
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let gray: Rgb<f32> = Rgb::new(0.5, 0.5, 0.5);
+    /// let blue: Rgb<f32> = Rgb::new(0.0, 0.0, 1.0);
+    ///
+    /// assert!((gray.perceived_brightness() - 0.5).abs() < 1e-6);
+    /// assert!(blue.perceived_brightness() < 0.5);
+    /// ```
+    ///
+    /// [hsp]: https://alienryderflex.com/hsp.html
+    fn perceived_brightness(&self) -> T::Channel
+    where
+        T::Channel: Float,
+    {
+        let f = <T::Channel as Float>::from_f64;
+        let Rgb { r, g, b } = self.luminance_rgb();
+        (f(0.299) * r * r + f(0.587) * g * g + f(0.114) * b * b).powf(f(0.5))
+    }
+
+    /// Gets the absolute luminance, in cd/m², of this color on a display with the given
+    /// peak luminance, using [`Luminance::wcag_relative_luminance`].
+    ///
+    /// ```
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let white: Rgb<f32> = Rgb::new(1.0, 1.0, 1.0);
+    /// let black: Rgb<f32> = Rgb::new(0.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(white.absolute_luminance(250.0), 250.0);
+    /// assert_eq!(black.absolute_luminance(250.0), 0.0);
+    /// ```
+    fn absolute_luminance(&self, peak_nits: T::Weighted) -> T::Weighted
+    where
+        T::Channel: Float,
+        T::Weighted: Float,
+    {
+        self.absolute_luminance_with_black(peak_nits, <T::Weighted as Float>::from_f64(0.0))
+    }
+
+    /// Like [`Luminance::absolute_luminance`], but for a display that doesn't produce
+    /// true black.
+    ///
+    /// ```
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let white: Rgb<f32> = Rgb::new(1.0, 1.0, 1.0);
+    /// let black: Rgb<f32> = Rgb::new(0.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(white.absolute_luminance_with_black(250.0, 0.5), 250.0);
+    /// assert_eq!(black.absolute_luminance_with_black(250.0, 0.5), 0.5);
+    /// ```
+    fn absolute_luminance_with_black(
+        &self,
+        peak_nits: T::Weighted,
+        black_nits: T::Weighted,
+    ) -> T::Weighted
+    where
+        T::Channel: Float,
+        T::Weighted: Float,
+    {
+        black_nits + (peak_nits - black_nits) * self.wcag_relative_luminance()
+    }
+
+    /// Gets the [WCAG contrast ratio][wcag-contrast] between this color and another,
+    /// using [`Luminance::wcag_relative_luminance`].
+    ///
+    /// The result is in the range [1.0, 21.0], and doesn't depend on which of the two
+    /// colors is lighter.
+    ///
+    /// ```
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let black: Rgb<f64> = Rgb::new(0.0, 0.0, 0.0);
+    /// let white: Rgb<f64> = Rgb::new(1.0, 1.0, 1.0);
+    ///
+    /// assert!((black.contrast_ratio(&white) - 21.0).abs() < 1e-9);
+    /// assert_eq!(black.contrast_ratio(&white), white.contrast_ratio(&black));
+    /// assert_eq!(white.contrast_ratio(&white), 1.0);
+    /// ```
+    ///
+    /// The other color can be any type with the same [`LuminanceValue`], so different
+    /// color types can be compared directly.
+    ///
+    /// ```
+    /// use relative_luminance::{Hsl, Luminance, Rgb};
+    ///
+    /// let white: Rgb<f32> = Rgb::new(1.0, 1.0, 1.0);
+    /// let navy: Hsl<f32> = Hsl::new(240.0, 1.0, 0.25);
+    ///
+    /// assert!(white.contrast_ratio(&navy) > 14.0);
+    /// assert_eq!(white.contrast_ratio(&navy), navy.contrast_ratio(&white));
+    /// ```
+    ///
+    /// [wcag-contrast]: https://www.w3.org/TR/WCAG22/#dfn-contrast-ratio
+    fn contrast_ratio<O: Luminance<T> + ?Sized>(&self, other: &O) -> T::Weighted
+    where
+        T::Channel: Float,
+        T::Weighted: Float,
+    {
+        contrast::ratio_from_luminance(
+            self.wcag_relative_luminance(),
+            other.wcag_relative_luminance(),
+        )
+    }
+
+    /// Checks if this color has a higher
+    /// [WCAG relative luminance](Luminance::wcag_relative_luminance) than another.
+    ///
+    /// The luminances are linearized, like by [`Luminance::contrast_ratio`], so this
+    /// agrees with which color is lighter in the contrast checks.
+    ///
+    /// ```
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let green: Rgb<f32> = Rgb::new(0.0, 1.0, 0.0);
+    /// let red: Rgb<f32> = Rgb::new(1.0, 0.0, 0.0);
+    ///
+    /// assert!(green.is_lighter_than(&red));
+    /// assert!(!green.is_lighter_than(&green));
+    /// ```
+    fn is_lighter_than<O: Luminance<T> + ?Sized>(&self, other: &O) -> bool
+    where
+        T::Channel: Float,
+        T::Weighted: PartialOrd,
+    {
+        self.wcag_relative_luminance() > other.wcag_relative_luminance()
+    }
+
+    /// Checks if this color has a lower
+    /// [WCAG relative luminance](Luminance::wcag_relative_luminance) than another.
+    fn is_darker_than<O: Luminance<T> + ?Sized>(&self, other: &O) -> bool
+    where
+        T::Channel: Float,
+        T::Weighted: PartialOrd,
+    {
+        self.wcag_relative_luminance() < other.wcag_relative_luminance()
+    }
+
+    /// Checks if this color's WCAG relative luminance is more than `epsilon` higher than
+    /// another's, so nearly equal colors aren't considered lighter.
+    ///
+    /// ```
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let gray: Rgb<f32> = Rgb::new(0.5, 0.5, 0.5);
+    /// let lighter: Rgb<f32> = Rgb::new(0.501, 0.501, 0.501);
+    ///
+    /// assert!(lighter.is_lighter_than(&gray));
+    /// assert!(!lighter.is_lighter_than_by(&gray, 0.01));
+    /// assert!(!gray.is_darker_than_by(&lighter, 0.01));
+    /// ```
+    fn is_lighter_than_by<O: Luminance<T> + ?Sized>(&self, other: &O, epsilon: T::Weighted) -> bool
+    where
+        T::Channel: Float,
+        T::Weighted: PartialOrd,
+    {
+        self.wcag_relative_luminance() > other.wcag_relative_luminance() + epsilon
+    }
+
+    /// Checks if this color's WCAG relative luminance is more than `epsilon` lower than
+    /// another's, so nearly equal colors aren't considered darker.
+    fn is_darker_than_by<O: Luminance<T> + ?Sized>(&self, other: &O, epsilon: T::Weighted) -> bool
+    where
+        T::Channel: Float,
+        T::Weighted: PartialOrd,
+    {
+        self.wcag_relative_luminance() + epsilon < other.wcag_relative_luminance()
+    }
+
+    /// Darkens this color until its
+    /// [WCAG relative luminance](Luminance::wcag_relative_luminance) is `luminance`,
+    /// and gets it encoded with [`transfer::Srgb`].
+    ///
+    /// The linear channels are scaled toward black, so the chromaticity stays the same.
+    /// Colors that are already as dark or darker aren't changed.
+    ///
+    /// ```
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let orange: Rgb<f64> = Rgb::new(1.0, 0.5, 0.0);
+    /// let darkened = orange.darken_to_luminance(0.1);
+    ///
+    /// assert!((darkened.wcag_relative_luminance() - 0.1).abs() < 1e-9);
+    /// assert_eq!(darkened.b, 0.0);
+    /// ```
+    fn darken_to_luminance(&self, luminance: T::Weighted) -> Rgb<T>
+    where
+        T: LuminanceValue<Weighted = <T as LuminanceValue>::Channel>,
+        T::Channel: Float,
+    {
+        let linear = self.linear_rgb();
+        let current = linear.relative_luminance();
+        let zero = T::Channel::from_f64(0.0);
+        let scale = if luminance >= current {
+            T::Channel::from_f64(1.0)
+        } else if luminance <= zero {
+            zero
+        } else {
+            luminance / current
+        };
+        Rgb::new(linear.r * scale, linear.g * scale, linear.b * scale).encode(&transfer::Srgb)
+    }
+
+    /// Lightens this color until its
+    /// [WCAG relative luminance](Luminance::wcag_relative_luminance) is `luminance`,
+    /// and gets it encoded with [`transfer::Srgb`].
+    ///
+    /// The linear channels are mixed with white. Colors that are already as light or
+    /// lighter aren't changed.
+    ///
+    /// ```
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let navy: Rgb<f64> = Rgb::new(0.0, 0.0, 0.5);
+    /// let lightened = navy.lighten_to_luminance(0.5);
+    ///
+    /// assert!((lightened.wcag_relative_luminance() - 0.5).abs() < 1e-9);
+    /// assert!(lightened.b > lightened.r);
+    /// ```
+    fn lighten_to_luminance(&self, luminance: T::Weighted) -> Rgb<T>
+    where
+        T: LuminanceValue<Weighted = <T as LuminanceValue>::Channel>,
+        T::Channel: Float,
+    {
+        let linear = self.linear_rgb();
+        let current = linear.relative_luminance();
+        let one = T::Channel::from_f64(1.0);
+        let amount = if luminance <= current {
+            T::Channel::from_f64(0.0)
+        } else if luminance >= one {
+            one
+        } else {
+            (luminance - current) / (one - current)
+        };
+        let mix = |channel: T::Channel| channel + (one - channel) * amount;
+        Rgb::new(mix(linear.r), mix(linear.g), mix(linear.b)).encode(&transfer::Srgb)
+    }
+
+    /// Reduces the saturation of this color by `amount`, from 0.0 for no change to 1.0
+    /// for gray, without changing its
+    /// [WCAG relative luminance](Luminance::wcag_relative_luminance), and gets it
+    /// encoded with [`transfer::Srgb`].
+    ///
+    /// The linear channels are mixed with the gray of the same luminance, so the color
+    /// stays in gamut and keeps its contrast with every other color. Amounts outside of
+    /// [0.0, 1.0] are clamped.
+    ///
+    /// ```
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let brand: Rgb<f64> = Rgb::new(0.9, 0.2, 0.4);
+    /// let muted = brand.desaturate(0.6);
+    ///
+    /// assert!((muted.wcag_relative_luminance() - brand.wcag_relative_luminance()).abs() < 1e-9);
+    /// assert!(muted.r < brand.r && muted.g > brand.g);
+    /// ```
+    fn desaturate(&self, amount: T::Channel) -> Rgb<T>
+    where
+        T: LuminanceValue<Weighted = <T as LuminanceValue>::Channel>,
+        T::Channel: Float,
+    {
+        let linear = self.linear_rgb();
+        let gray = linear.relative_luminance();
+        let (zero, one) = (T::Channel::from_f64(0.0), T::Channel::from_f64(1.0));
+        let amount = if amount < zero {
+            zero
+        } else if amount > one {
+            one
+        } else {
+            amount
+        };
+        let mix = |channel: T::Channel| channel + (gray - channel) * amount;
+        Rgb::new(mix(linear.r), mix(linear.g), mix(linear.b)).encode(&transfer::Srgb)
+    }
+
+    /// Maps the [L\*](Luminance::relative_lightness) of this color to 100 − L\*,
+    /// keeping its CIELAB hue and chroma, and gets it encoded with [`transfer::Srgb`].
+    ///
+    /// This turns the colors of a light palette into the colors of a dark palette, so
+    /// light backgrounds become dark and dark text becomes light. Chroma that doesn't
+    /// fit in the sRGB gamut at the new lightness is reduced.
+    ///
+    /// ```
+    /// use relative_luminance::{Luminance, Rgb};
+    ///
+    /// let paper: Rgb<f64> = Rgb::new(0.98, 0.96, 0.9);
+    /// let ink: Rgb<f64> = Rgb::new(0.1, 0.1, 0.3);
+    /// let (night, glow) = (paper.invert_lightness(), ink.invert_lightness());
+    ///
+    /// assert!((paper.relative_lightness() + night.relative_lightness() - 100.0).abs() < 1e-3);
+    /// assert!(night.is_darker_than(&glow));
+    /// assert!(glow.b > glow.r);
+    /// ```
+    fn invert_lightness(&self) -> Rgb<T>
+    where
+        T::Channel: Float,
+    {
+        let linear = self.linear_rgb();
+        let [r, g, b] = lab::invert_lightness([linear.r, linear.g, linear.b]);
+        Rgb::new(r, g, b).encode(&transfer::Srgb)
+    }
 }
 
 impl<T: LuminanceValue> Luminance<T> for Rgb<T> {
@@ -215,4 +911,107 @@ mod tests {
             Luminance::relative_luminance(&rgb)
         );
     }
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn test_zerocopy_view() {
+        use ::zerocopy::{FromBytes, IntoBytes};
+
+        let bytes = [255, 0, 0, 0, 0, 255];
+        let pixels = <[Rgb<u8>]>::ref_from_bytes(&bytes).unwrap();
+        assert_eq!(pixels.len(), 2);
+        assert_eq!(pixels[1].relative_luminance(), 0.0722);
+        assert_eq!(pixels.as_bytes(), bytes);
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv_round_trip() {
+        let rgb = Rgb::<f32>::new(0.25, 0.5, 0.75);
+        let bytes = ::rkyv::to_bytes::<::rkyv::rancor::Error>(&rgb).unwrap();
+        let archived = ::rkyv::access::<ArchivedRgb<f32>, ::rkyv::rancor::Error>(&bytes).unwrap();
+        assert_eq!(archived.g, 0.5);
+        let round_trip: Rgb<f32> =
+            ::rkyv::deserialize::<_, ::rkyv::rancor::Error>(archived).unwrap();
+        assert_eq!(round_trip.relative_luminance(), rgb.relative_luminance());
+        let weights = Weights::<f64>::default();
+        let bytes = ::rkyv::to_bytes::<::rkyv::rancor::Error>(&weights).unwrap();
+        let round_trip = ::rkyv::from_bytes::<Weights<f64>, ::rkyv::rancor::Error>(&bytes);
+        assert_eq!(round_trip.unwrap(), weights);
+    }
+
+    #[test]
+    fn test_default_weights_equal_relative_luminance() {
+        let rgb = Rgb::<f32>::new(0.25, 0.5, 0.75);
+        assert_eq!(
+            rgb.relative_luminance_with(&Weights::default()),
+            rgb.relative_luminance()
+        );
+    }
+
+    #[test]
+    fn test_wcag_relative_luminance() {
+        let black = Rgb::<f64>::new(0.0, 0.0, 0.0);
+        let white = Rgb::<f64>::new(1.0, 1.0, 1.0);
+        // #777777
+        let gray = Rgb::<f64>::new(119.0 / 255.0, 119.0 / 255.0, 119.0 / 255.0);
+        assert_eq!(black.wcag_relative_luminance(), 0.0);
+        assert!((white.wcag_relative_luminance() - 1.0).abs() < 1e-12);
+        assert!((gray.wcag_relative_luminance() - 0.184474994).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_is_lighter_than_integers() {
+        let green = Rgb::<f32>::from(Rgb::<u8>::new(0, 255, 0));
+        let red = Rgb::<f32>::from(Rgb::<u8>::new(255, 0, 0));
+        assert!(green.is_lighter_than(&red) && red.is_darker_than(&green));
+        let almost_green = Rgb::<f32>::from(Rgb::<u8>::new(0, 254, 0));
+        assert!(!green.is_lighter_than_by(&almost_green, 1.0));
+    }
+
+    #[test]
+    fn test_is_lighter_than_compares_linear_luminance() {
+        // The encoded weighted sums are 0.2848 and 0.2857, but magenta has about four
+        // times the WCAG luminance.
+        let magenta = Rgb::<f64>::new(1.0, 0.0, 1.0);
+        let green_gray = Rgb::<f64>::new(0.25, 0.3, 0.25);
+        assert!(magenta.is_lighter_than(&green_gray));
+        assert!(green_gray.is_darker_than(&magenta));
+        assert!(magenta.is_lighter_than_by(&green_gray, 0.2));
+    }
+
+    #[test]
+    fn test_darken_and_lighten_only_move_one_way() {
+        let gray = Rgb::<f64>::new(0.5, 0.5, 0.5);
+        assert!((gray.darken_to_luminance(0.9).r - 0.5).abs() < 1e-9);
+        assert!((gray.lighten_to_luminance(0.1).r - 0.5).abs() < 1e-9);
+        assert_eq!(gray.darken_to_luminance(-1.0).r, 0.0);
+        assert!((gray.lighten_to_luminance(2.0).r - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fully_desaturated_is_gray() {
+        let color = Rgb::<f64>::new(0.2, 0.6, 0.9);
+        let gray = color.desaturate(2.0);
+        assert!((gray.r - gray.g).abs() < 1e-9 && (gray.g - gray.b).abs() < 1e-9);
+        assert!((gray.wcag_relative_luminance() - color.wcag_relative_luminance()).abs() < 1e-9);
+        assert!((color.desaturate(-1.0).g - 0.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_relative_lightness() {
+        let black = Rgb::<f64>::new(0.0, 0.0, 0.0);
+        let white = Rgb::<f64>::new(1.0, 1.0, 1.0);
+        assert_eq!(black.relative_lightness(), 0.0);
+        assert!((white.relative_lightness() - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        // #777777 on white is a well-known borderline case for AA.
+        let gray = Rgb::<f64>::new(119.0 / 255.0, 119.0 / 255.0, 119.0 / 255.0);
+        let white = Rgb::<f64>::new(1.0, 1.0, 1.0);
+        assert!((gray.contrast_ratio(&white) - 4.478).abs() < 1e-3);
+        assert_eq!(gray.contrast_ratio(&white), white.contrast_ratio(&gray));
+    }
 }
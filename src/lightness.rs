@@ -0,0 +1,217 @@
+//! Conversions between relative luminance (Y) and [CIE L\*][lightness] perceptual
+//! lightness.
+//!
+//! These are useful for generating color scales, where evenly spaced lightness looks
+//! more even than evenly spaced luminance.
+//!
+//! ```
+//! use relative_luminance::lightness::{lstar_to_y, y_to_lstar};
+//!
+//! let y = 0.18f64;
+//! let lstar = y_to_lstar(y);
+//!
+//! assert!((lstar - 49.5).abs() < 0.1);
+//! assert!((lstar_to_y(lstar) - y).abs() < 1e-9);
+//! ```
+//!
+//! [lightness]: https://en.wikipedia.org/wiki/CIELAB_color_space#Perceptual_differences
+use crate::lab::{self, Lab, Lch};
+use crate::transfer::Srgb;
+use crate::{polar, Float, Luminance, LuminanceValue, Rgb};
+
+/// The luminance below which L\* is linear in Y.
+const EPSILON: f64 = 0.008856;
+/// The slope of the linear segment.
+const KAPPA: f64 = 903.3;
+
+/// Converts a relative luminance (Y) in [0.0, 1.0] to L\* in [0.0, 100.0].
+///
+/// ```
+/// # use relative_luminance::lightness::y_to_lstar;
+/// assert_eq!(y_to_lstar(0.0f32), 0.0);
+/// assert_eq!(y_to_lstar(1.0f32), 100.0);
+/// ```
+pub fn y_to_lstar<F: Float>(y: F) -> F {
+    if y > F::from_f64(EPSILON) {
+        F::from_f64(116.0) * y.powf(F::from_f64(1.0 / 3.0)) - F::from_f64(16.0)
+    } else {
+        F::from_f64(KAPPA) * y
+    }
+}
+
+/// Converts L\* in [0.0, 100.0] to a relative luminance (Y) in [0.0, 1.0].
+///
+/// ```
+/// # use relative_luminance::lightness::lstar_to_y;
+/// assert_eq!(lstar_to_y(0.0f32), 0.0);
+/// assert_eq!(lstar_to_y(100.0f32), 1.0);
+/// ```
+pub fn lstar_to_y<F: Float>(lstar: F) -> F {
+    if lstar > F::from_f64(KAPPA * EPSILON) {
+        ((lstar + F::from_f64(16.0)) / F::from_f64(116.0)).powf(F::from_f64(3.0))
+    } else {
+        lstar / F::from_f64(KAPPA)
+    }
+}
+
+/// A range of perceived brightness, from [`Luminance::classify`].
+///
+/// [`Luminance::classify`]: crate::Luminance::classify
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum Brightness {
+    /// Near black.
+    VeryDark,
+    /// Darker than the middle.
+    Dark,
+    /// Around the middle.
+    Mid,
+    /// Lighter than the middle.
+    Light,
+    /// Near white.
+    VeryLight,
+}
+
+/// The L\* lightnesses where each [`Brightness`] starts, in [0.0, 100.0].
+///
+/// The default splits L\* into 5 equal bands, at 20, 40, 60, and 80.
+///
+/// ```
+/// use relative_luminance::lightness::{Bands, Brightness};
+///
+/// let bands = Bands::<f32>::default();
+///
+/// assert_eq!(bands.classify(10.0), Brightness::VeryDark);
+/// assert_eq!(bands.classify(60.0), Brightness::Light);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Bands<F> {
+    /// The lightness where [`Brightness::Dark`] starts.
+    pub dark: F,
+    /// The lightness where [`Brightness::Mid`] starts.
+    pub mid: F,
+    /// The lightness where [`Brightness::Light`] starts.
+    pub light: F,
+    /// The lightness where [`Brightness::VeryLight`] starts.
+    pub very_light: F,
+}
+
+impl<F: Float> Bands<F> {
+    /// Creates new `Bands<F>`, which should be in increasing order.
+    pub fn new(dark: F, mid: F, light: F, very_light: F) -> Self {
+        Bands {
+            dark,
+            mid,
+            light,
+            very_light,
+        }
+    }
+
+    /// Gets the brightness of an L\* lightness.
+    pub fn classify(&self, lstar: F) -> Brightness {
+        if lstar >= self.very_light {
+            Brightness::VeryLight
+        } else if lstar >= self.light {
+            Brightness::Light
+        } else if lstar >= self.mid {
+            Brightness::Mid
+        } else if lstar >= self.dark {
+            Brightness::Dark
+        } else {
+            Brightness::VeryDark
+        }
+    }
+}
+
+impl<F: Float> Default for Bands<F> {
+    fn default() -> Self {
+        let f = F::from_f64;
+        Bands::new(f(20.0), f(40.0), f(60.0), f(80.0))
+    }
+}
+
+/// Generates `count` colors between two colors, not including them, with evenly spaced
+/// [L\*](Luminance::relative_lightness).
+///
+/// The colors are interpolated in CIELAB, where steps of lightness look even, unlike
+/// steps of sRGB channels, which bunch up at the light end. Chroma that doesn't fit in
+/// the sRGB gamut is reduced, without changing the lightness. The colors are encoded
+/// with [`Srgb`].
+///
+/// ```
+/// use relative_luminance::{lightness, Luminance, Rgb};
+///
+/// let black: Rgb<f64> = Rgb::new(0.0, 0.0, 0.0);
+/// let white: Rgb<f64> = Rgb::new(1.0, 1.0, 1.0);
+/// let grays = lightness::ramp(&black, &white, 3);
+///
+/// assert_eq!(grays.len(), 3);
+/// assert!((grays[0].relative_lightness() - 25.0).abs() < 1e-3);
+/// assert!((grays[2].relative_lightness() - 75.0).abs() < 1e-3);
+/// ```
+pub fn ramp<F, Start, End>(start: &Start, end: &End, count: usize) -> Vec<Rgb<F>>
+where
+    F: Float + LuminanceValue<Channel = F>,
+    Start: Luminance<F> + ?Sized,
+    End: Luminance<F> + ?Sized,
+{
+    let to_lab = |color: Rgb<F>| lab::from_linear_srgb([color.r, color.g, color.b]);
+    let (start, end) = (to_lab(start.linear_rgb()), to_lab(end.linear_rgb()));
+    (1..=count)
+        .map(|i| {
+            let t = F::from_f64(i as f64 / (count + 1) as f64);
+            let mix = |a: F, b: F| a + (b - a) * t;
+            let color = Lab::new(
+                mix(start.l, end.l),
+                mix(start.a, end.a),
+                mix(start.b, end.b),
+            );
+            let [r, g, b] = polar::gamut_map_lch(Lch::from_lab(&color));
+            Rgb::new(r, g, b).encode(&Srgb)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trips() {
+        for i in 0..=100 {
+            let lstar = f64::from(i);
+            let round_trip = y_to_lstar(lstar_to_y(lstar));
+            assert!(
+                (round_trip - lstar).abs() < 0.001,
+                "{lstar} != {round_trip}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_bands_start_at_thresholds() {
+        let bands = Bands::<f64>::default();
+        assert_eq!(bands.classify(0.0), Brightness::VeryDark);
+        assert_eq!(bands.classify(20.0), Brightness::Dark);
+        assert_eq!(bands.classify(59.99), Brightness::Mid);
+        assert_eq!(bands.classify(80.0), Brightness::VeryLight);
+        assert!(Brightness::Dark < Brightness::Light);
+    }
+
+    #[test]
+    fn test_ramp_steps_are_even() {
+        let navy = Rgb::<f64>::new(0.0, 0.0, 0.3);
+        let yellow = Rgb::<f64>::new(1.0, 0.9, 0.2);
+        let lightnesses: Vec<_> = ramp(&navy, &yellow, 4)
+            .iter()
+            .map(|color| color.relative_lightness())
+            .collect();
+        let step = (yellow.relative_lightness() - navy.relative_lightness()) / 5.0;
+        for (i, lstar) in lightnesses.iter().enumerate() {
+            let expected = navy.relative_lightness() + step * (i + 1) as f64;
+            assert!((lstar - expected).abs() < 1e-3, "{lstar} != {expected}");
+        }
+        assert!(ramp(&navy, &yellow, 0).is_empty());
+    }
+}
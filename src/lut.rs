@@ -0,0 +1,46 @@
+//! Lookup tables for the relative luminance of 8-bit channels.
+//!
+//! Each table holds the weighted contribution of every channel value, so the
+//! luminance of a pixel is three lookups and two additions. This gives the same
+//! results as [`Rgb<u8>`](crate::Rgb), which normalizes and weighs each channel.
+//!
+//! Which one is faster depends on the target and the cache, so compare them with
+//! `cargo bench --features lut` before relying on the tables.
+//!
+//! ```
+//! use relative_luminance::{lut, Luminance, Rgb};
+//!
+//! let color: Rgb<u8> = Rgb::new(51, 102, 153);
+//!
+//! assert_eq!(lut::relative_luminance(51, 102, 153), color.relative_luminance());
+//! ```
+
+/// The weighted contributions of the red channel.
+pub static RED: [f32; 256] = luminance_table!(|value| [value as u8, 0, 0]);
+/// The weighted contributions of the green channel.
+pub static GREEN: [f32; 256] = luminance_table!(|value| [0, value as u8, 0]);
+/// The weighted contributions of the blue channel.
+pub static BLUE: [f32; 256] = luminance_table!(|value| [0, 0, value as u8]);
+
+/// Gets the relative luminance of 8-bit channels from the lookup tables.
+#[inline]
+pub fn relative_luminance(r: u8, g: u8, b: u8) -> f32 {
+    RED[usize::from(r)] + GREEN[usize::from(g)] + BLUE[usize::from(b)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rgb;
+
+    #[test]
+    fn test_matches_rgb_u8() {
+        for value in 0..=255 {
+            let color = Rgb::<u8>::new(value, value / 3, 255 - value);
+            assert_eq!(
+                relative_luminance(color.r, color.g, color.b),
+                crate::Luminance::relative_luminance(&color)
+            );
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{Float, Luminance, LuminanceValue, Rgb};
+use ::mint::Vector3;
+
+/// Vectors from the [`mint`](::mint) crate, with linear RGB channels in `x`, `y`, and
+/// `z`.
+///
+/// This lets any math library that converts its vectors to `mint` types be used. The
+/// channels are already linear, so they aren't decoded again by
+/// [`Luminance::linear_rgb`].
+///
+/// ```
+/// use relative_luminance::Luminance;
+///
+/// let gray = mint::Vector3 { x: 0.5f32, y: 0.5, z: 0.5 };
+///
+/// assert!((gray.wcag_relative_luminance() - 0.5).abs() < 1e-6);
+/// ```
+impl<T: LuminanceValue<Channel = T>> Luminance<T> for Vector3<T> {
+    fn luminance_rgb(&self) -> Rgb<T> {
+        Rgb::new(self.x, self.y, self.z)
+    }
+
+    fn linear_rgb(&self) -> Rgb<T>
+    where
+        T::Channel: Float,
+    {
+        self.luminance_rgb()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channels() {
+        let green: Vector3<f32> = [0.0, 1.0, 0.0].into();
+        assert_eq!(green.relative_luminance(), 0.7152);
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{Float, Luminance, LuminanceValue, Rgb};
+use ::nalgebra::{Scalar, Vector3};
+
+/// Vectors from the [`nalgebra`](::nalgebra) crate, with linear RGB channels in `x`,
+/// `y`, and `z`.
+///
+/// The channels are already linear, so they aren't decoded again by
+/// [`Luminance::linear_rgb`].
+///
+/// ```
+/// use nalgebra::Vector3;
+/// use relative_luminance::Luminance;
+///
+/// let gray = Vector3::new(0.5f32, 0.5, 0.5);
+///
+/// assert!((gray.wcag_relative_luminance() - 0.5).abs() < 1e-6);
+/// ```
+impl<T: LuminanceValue<Channel = T> + Scalar> Luminance<T> for Vector3<T> {
+    fn luminance_rgb(&self) -> Rgb<T> {
+        Rgb::new(self.x, self.y, self.z)
+    }
+
+    fn linear_rgb(&self) -> Rgb<T>
+    where
+        T::Channel: Float,
+    {
+        self.luminance_rgb()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channels() {
+        assert_eq!(Vector3::new(0.0f32, 1.0, 0.0).relative_luminance(), 0.7152);
+        assert_eq!(Vector3::<f64>::z().relative_luminance(), 0.0722);
+    }
+}
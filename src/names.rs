@@ -0,0 +1,247 @@
+//! The 148 named colors of [CSS][css-named-colors], with precomputed luminances.
+//!
+//! The CSS names are based on the X11 color names, but some X11 colors, like `gray`,
+//! have different values in CSS.
+//!
+//! ```
+//! use relative_luminance::names;
+//!
+//! let purple = names::lookup("RebeccaPurple").unwrap();
+//!
+//! assert_eq!((purple.rgb.r, purple.rgb.g, purple.rgb.b), (102, 51, 153));
+//! assert!(purple.wcag_relative_luminance < 0.18);
+//! assert!(names::lookup("not a color").is_none());
+//! ```
+//!
+//! [css-named-colors]: https://www.w3.org/TR/css-color-4/#named-colors
+use crate::Rgb;
+use core::cmp::Ordering;
+
+/// A named color.
+#[derive(Clone, Copy, Debug)]
+pub struct NamedColor {
+    /// The lowercase name of the color, like `"rebeccapurple"`.
+    pub name: &'static str,
+    /// The 8-bit sRGB channels of the color.
+    pub rgb: Rgb<u8>,
+    /// The precomputed [`Luminance::relative_luminance`](crate::Luminance::relative_luminance).
+    pub relative_luminance: f32,
+    /// The precomputed
+    /// [`Luminance::wcag_relative_luminance`](crate::Luminance::wcag_relative_luminance).
+    pub wcag_relative_luminance: f32,
+}
+
+const fn color(
+    name: &'static str,
+    r: u8,
+    g: u8,
+    b: u8,
+    relative_luminance: f32,
+    wcag_relative_luminance: f32,
+) -> NamedColor {
+    NamedColor {
+        name,
+        rgb: Rgb { r, g, b },
+        relative_luminance,
+        wcag_relative_luminance,
+    }
+}
+
+/// Every named color, sorted by name.
+///
+/// Both spellings of the grays, like `gray` and `grey`, are included.
+pub static COLORS: [NamedColor; 148] = [
+    color("aliceblue", 240, 248, 255, 0.9678612, 0.9288007),
+    color("antiquewhite", 250, 235, 215, 0.9284118, 0.8464695),
+    color("aqua", 0, 255, 255, 0.7874, 0.7874),
+    color("aquamarine", 127, 255, 212, 0.8811082, 0.80785495),
+    color("azure", 240, 255, 255, 0.9874941, 0.9726527),
+    color("beige", 245, 245, 220, 0.9537059, 0.898846),
+    color("bisque", 255, 228, 196, 0.90756786, 0.8073233),
+    color("black", 0, 0, 0, 0.0, 0.0),
+    color("blanchedalmond", 255, 235, 205, 0.929749, 0.8508444),
+    color("blue", 0, 0, 255, 0.0722, 0.0722),
+    color("blueviolet", 138, 43, 226, 0.29964548, 0.12622014),
+    color("brown", 165, 42, 42, 0.2672541, 0.09822429),
+    color("burlywood", 222, 184, 135, 0.7393765, 0.5155984),
+    color("cadetblue", 95, 158, 160, 0.5676494, 0.29424682),
+    color("chartreuse", 127, 255, 0, 0.8210831, 0.76032025),
+    color("chocolate", 210, 105, 30, 0.4780706, 0.23898526),
+    color("coral", 255, 127, 80, 0.5914486, 0.3701793),
+    color("cornflowerblue", 100, 149, 237, 0.56837726, 0.30318642),
+    color("cornsilk", 255, 248, 220, 0.97045726, 0.9356211),
+    color("crimson", 220, 20, 60, 0.25650197, 0.160422),
+    color("cyan", 0, 255, 255, 0.7874, 0.7874),
+    color("darkblue", 0, 0, 139, 0.03935608, 0.018640801),
+    color("darkcyan", 0, 139, 139, 0.4292102, 0.20329317),
+    color("darkgoldenrod", 184, 134, 11, 0.5323506, 0.27264702),
+    color("darkgray", 169, 169, 169, 0.6627451, 0.39675522),
+    color("darkgreen", 0, 100, 0, 0.28047058, 0.09114343),
+    color("darkgrey", 169, 169, 169, 0.6627451, 0.39675522),
+    color("darkkhaki", 189, 183, 107, 0.701131, 0.45747328),
+    color("darkmagenta", 139, 0, 139, 0.15524392, 0.07353047),
+    color("darkolivegreen", 85, 107, 47, 0.38427764, 0.1265192),
+    color("darkorange", 255, 140, 0, 0.6052588, 0.40016168),
+    color("darkorchid", 153, 50, 204, 0.3255553, 0.13413142),
+    color("darkred", 139, 0, 0, 0.11588784, 0.054889675),
+    color("darksalmon", 233, 150, 122, 0.6495067, 0.40541473),
+    color("darkseagreen", 143, 188, 143, 0.6869961, 0.4378925),
+    color("darkslateblue", 72, 61, 139, 0.27047136, 0.06579284),
+    color("darkslategray", 47, 79, 79, 0.28312472, 0.067608155),
+    color("darkslategrey", 47, 79, 79, 0.28312472, 0.067608155),
+    color("darkturquoise", 0, 206, 209, 0.63694507, 0.4874606),
+    color("darkviolet", 148, 0, 211, 0.18313333, 0.109990485),
+    color("deeppink", 255, 20, 147, 0.31031528, 0.23866896),
+    color("deepskyblue", 0, 191, 255, 0.60789883, 0.44481602),
+    color("dimgray", 105, 105, 105, 0.4117647, 0.14126329),
+    color("dimgrey", 105, 105, 105, 0.4117647, 0.14126329),
+    color("dodgerblue", 30, 144, 255, 0.5010894, 0.27442536),
+    color("firebrick", 178, 34, 34, 0.2533898, 0.10724526),
+    color("floralwhite", 255, 250, 240, 0.9817294, 0.9592248),
+    color("forestgreen", 34, 139, 34, 0.42782745, 0.18920812),
+    color("fuchsia", 255, 0, 255, 0.2848, 0.2848),
+    color("gainsboro", 220, 220, 220, 0.8627451, 0.7156935),
+    color("ghostwhite", 248, 248, 255, 0.974531, 0.9431126),
+    color("gold", 255, 215, 0, 0.8156118, 0.69860876),
+    color("goldenrod", 218, 165, 32, 0.653589, 0.41919976),
+    color("gray", 128, 128, 128, 0.5019608, 0.2158605),
+    color("green", 0, 128, 0, 0.35900235, 0.15438344),
+    color("greenyellow", 173, 255, 47, 0.87274194, 0.8060947),
+    color("grey", 128, 128, 128, 0.5019608, 0.2158605),
+    color("honeydew", 240, 255, 240, 0.98324704, 0.9633654),
+    color("hotpink", 255, 105, 180, 0.5580588, 0.34658438),
+    color("indianred", 205, 92, 92, 0.4549953, 0.21406135),
+    color("indigo", 75, 0, 130, 0.09933726, 0.031075615),
+    color("ivory", 255, 255, 240, 0.99575293, 0.9907127),
+    color("khaki", 240, 230, 140, 0.8848157, 0.7701234),
+    color("lavender", 230, 230, 250, 0.9076235, 0.8031875),
+    color("lavenderblush", 255, 240, 245, 0.95509803, 0.9017275),
+    color("lawngreen", 124, 252, 0, 0.81016785, 0.7390589),
+    color("lemonchiffon", 255, 250, 205, 0.9718196, 0.94038993),
+    color("lightblue", 173, 216, 230, 0.81517255, 0.6370914),
+    color("lightcoral", 240, 128, 128, 0.59533805, 0.3552212),
+    color("lightcyan", 224, 255, 255, 0.97415453, 0.94587296),
+    color("lightgoldenrodyellow", 250, 250, 210, 0.9690667, 0.9334835),
+    color("lightgray", 211, 211, 211, 0.827451, 0.65140563),
+    color("lightgreen", 144, 238, 144, 0.8283482, 0.6909198),
+    color("lightgrey", 211, 211, 211, 0.827451, 0.65140563),
+    color("lightpink", 255, 182, 193, 0.777702, 0.58566153),
+    color("lightsalmon", 255, 160, 122, 0.6958957, 0.47806752),
+    color("lightseagreen", 32, 178, 170, 0.5740502, 0.35050145),
+    color("lightskyblue", 135, 206, 250, 0.76110667, 0.5619564),
+    color("lightslategray", 119, 136, 153, 0.52397335, 0.23830165),
+    color("lightslategrey", 119, 136, 153, 0.52397335, 0.23830165),
+    color("lightsteelblue", 176, 196, 222, 0.75931454, 0.5398389),
+    color("lightyellow", 255, 255, 224, 0.99122274, 0.98161817),
+    color("lime", 0, 255, 0, 0.7152, 0.7152),
+    color("limegreen", 50, 205, 50, 0.6308078, 0.44571042),
+    color("linen", 250, 240, 230, 0.94668233, 0.8835734),
+    color("magenta", 255, 0, 255, 0.2848, 0.2848),
+    color("maroon", 128, 0, 0, 0.106716864, 0.04589194),
+    color("mediumaquamarine", 102, 205, 170, 0.70813805, 0.49389702),
+    color("mediumblue", 0, 0, 205, 0.058043137, 0.04407778),
+    color("mediumorchid", 186, 85, 211, 0.4532149, 0.21639252),
+    color("mediumpurple", 147, 112, 219, 0.49869177, 0.22905858),
+    color("mediumseagreen", 60, 179, 113, 0.5840604, 0.3439311),
+    color("mediumslateblue", 123, 104, 238, 0.46162432, 0.20284629),
+    color("mediumspringgreen", 0, 250, 154, 0.7447796, 0.7070431),
+    color("mediumturquoise", 72, 209, 204, 0.70397174, 0.5133828),
+    color("mediumvioletred", 199, 21, 133, 0.26246744, 0.143719),
+    color("midnightblue", 25, 25, 112, 0.122672155, 0.020717867),
+    color("mintcream", 245, 255, 250, 0.9902471, 0.97834605),
+    color("mistyrose", 255, 228, 225, 0.9157788, 0.82183045),
+    color("moccasin", 255, 228, 181, 0.9033208, 0.80083),
+    color("navajowhite", 255, 222, 173, 0.88422745, 0.7651968),
+    color("navy", 0, 0, 128, 0.03624157, 0.015585128),
+    color("oldlace", 253, 245, 230, 0.96320707, 0.91900635),
+    color("olive", 128, 128, 0, 0.46571922, 0.20027538),
+    color("olivedrab", 107, 142, 35, 0.49738666, 0.22593151),
+    color("orange", 255, 165, 0, 0.6753765, 0.48170266),
+    color("orangered", 255, 69, 0, 0.4061247, 0.25516245),
+    color("orchid", 218, 112, 214, 0.5564706, 0.31348807),
+    color("palegoldenrod", 238, 232, 170, 0.8972518, 0.7879265),
+    color("palegreen", 152, 251, 152, 0.8737443, 0.77936757),
+    color("paleturquoise", 175, 238, 238, 0.88080865, 0.7643608),
+    color("palevioletred", 219, 112, 147, 0.53833413, 0.28754994),
+    color("papayawhip", 255, 239, 213, 0.94323295, 0.877971),
+    color("peachpuff", 255, 218, 185, 0.87640625, 0.7490559),
+    color("peru", 205, 133, 63, 0.56177723, 0.30113074),
+    color("pink", 255, 192, 203, 0.8085804, 0.6327107),
+    color("plum", 221, 160, 221, 0.6955796, 0.4573422),
+    color("powderblue", 176, 224, 230, 0.8401114, 0.68254584),
+    color("purple", 128, 0, 128, 0.14295843, 0.06147707),
+    color("rebeccapurple", 102, 51, 153, 0.2714, 0.07492341),
+    color("red", 255, 0, 0, 0.2126, 0.2126),
+    color("rosybrown", 188, 143, 143, 0.59830195, 0.32319456),
+    color("royalblue", 65, 105, 225, 0.41239217, 0.1666321),
+    color("saddlebrown", 139, 69, 19, 0.31479216, 0.09792229),
+    color("salmon", 250, 128, 114, 0.59971136, 0.3697724),
+    color("sandybrown", 244, 164, 96, 0.690582, 0.46628544),
+    color("seagreen", 46, 139, 87, 0.45283842, 0.197342),
+    color("seashell", 255, 245, 238, 0.9671396, 0.9273786),
+    color("sienna", 160, 82, 45, 0.37612313, 0.13697632),
+    color("silver", 192, 192, 192, 0.7529412, 0.5271151),
+    color("skyblue", 135, 206, 235, 0.7568596, 0.5529167),
+    color("slateblue", 106, 90, 205, 0.39884156, 0.14784278),
+    color("slategray", 112, 128, 144, 0.49315137, 0.20896704),
+    color("slategrey", 112, 128, 144, 0.49315137, 0.20896704),
+    color("snow", 255, 250, 250, 0.9845608, 0.9653334),
+    color("springgreen", 0, 255, 127, 0.7511584, 0.73052305),
+    color("steelblue", 70, 130, 180, 0.47393724, 0.20562643),
+    color("tan", 210, 180, 140, 0.7195686, 0.48237604),
+    color("teal", 0, 128, 128, 0.3952439, 0.16996856),
+    color("thistle", 216, 191, 216, 0.7769412, 0.568184),
+    color("tomato", 255, 99, 71, 0.51036865, 0.3063861),
+    color("turquoise", 64, 224, 208, 0.7405051, 0.58955365),
+    color("violet", 238, 130, 238, 0.6304251, 0.40315452),
+    color("wheat", 245, 222, 179, 0.87758905, 0.74909705),
+    color("white", 255, 255, 255, 1.0, 1.0),
+    color("whitesmoke", 245, 245, 245, 0.9607843, 0.91309863),
+    color("yellow", 255, 255, 0, 0.9278, 0.9278),
+    color("yellowgreen", 154, 205, 50, 0.7175153, 0.5076296),
+];
+
+/// Compares a lowercase name to a name in any case.
+fn compare_ignore_case(lowercase: &str, name: &str) -> Ordering {
+    lowercase
+        .bytes()
+        .cmp(name.bytes().map(|byte| byte.to_ascii_lowercase()))
+}
+
+/// Looks up a named color, ignoring ASCII case.
+pub fn lookup(name: &str) -> Option<&'static NamedColor> {
+    COLORS
+        .binary_search_by(|color| compare_ignore_case(color.name, name))
+        .ok()
+        .map(|index| &COLORS[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Luminance;
+
+    #[test]
+    fn test_precomputed_luminances() {
+        for color in &COLORS {
+            let computed = Rgb::<f64>::from(color.rgb);
+            let relative = computed.relative_luminance() as f32;
+            assert!((relative - color.relative_luminance).abs() < 1e-6);
+            let wcag = computed.wcag_relative_luminance() as f32;
+            assert!((wcag - color.wcag_relative_luminance).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_sorted() {
+        assert!(COLORS.windows(2).all(|pair| pair[0].name < pair[1].name));
+        assert_eq!(lookup("WHITE").unwrap().relative_luminance, 1.0);
+        assert_eq!(lookup("black").unwrap().wcag_relative_luminance, 0.0);
+    }
+
+    #[test]
+    fn test_parse_name() {
+        let red: Rgb<f32> = " Red ".parse().unwrap();
+        assert_eq!(red.relative_luminance(), 0.2126);
+    }
+}
@@ -0,0 +1,84 @@
+//! Luminance of images stored as [`ndarray`](::ndarray) arrays.
+//!
+//! Images are arrays with a shape of (height, width, channels), like the arrays
+//! used by many scientific imaging libraries. Each channel is handled like the
+//! channels of [`Rgb`], and a fourth alpha channel is ignored.
+//!
+//! ```
+//! use ndarray::array;
+//! use relative_luminance::ndarray::luminance_array_u8;
+//!
+//! // A 1×2 image of red and green pixels
+//! let image = array![[[255, 0, 0], [0, 255, 0]]];
+//!
+//! assert_eq!(luminance_array_u8(&image), array![[0.2126, 0.7152]]);
+//! ```
+use crate::{LuminanceValue, Rgb};
+use ::ndarray::{Array2, ArrayBase, Data, Ix3};
+
+/// Gets the luminance plane of an image with any channel type.
+fn luminance_plane<S, T>(image: &ArrayBase<S, Ix3>) -> Array2<f32>
+where
+    S: Data<Elem = T>,
+    T: LuminanceValue<Channel = T, Weighted = f32>,
+{
+    let (height, width, channels) = image.dim();
+    assert!(
+        channels == 3 || channels == 4,
+        "image must have 3 or 4 channels"
+    );
+    Array2::from_shape_fn((height, width), |(y, x)| {
+        Rgb::<T>::new(image[[y, x, 0]], image[[y, x, 1]], image[[y, x, 2]]).relative_luminance()
+    })
+}
+
+/// Gets the relative luminance of every pixel of an image with channels in
+/// [0.0, 1.0].
+///
+/// # Panics
+///
+/// Panics if the image doesn't have 3 or 4 channels.
+pub fn luminance_array<S: Data<Elem = f32>>(image: &ArrayBase<S, Ix3>) -> Array2<f32> {
+    luminance_plane(image)
+}
+
+/// Gets the relative luminance of every pixel of an image with 8-bit channels.
+///
+/// # Panics
+///
+/// Panics if the image doesn't have 3 or 4 channels.
+pub fn luminance_array_u8<S: Data<Elem = u8>>(image: &ArrayBase<S, Ix3>) -> Array2<f32> {
+    luminance_plane(image)
+}
+
+/// Gets the relative luminance of every pixel of an image with 16-bit channels.
+///
+/// # Panics
+///
+/// Panics if the image doesn't have 3 or 4 channels.
+pub fn luminance_array_u16<S: Data<Elem = u16>>(image: &ArrayBase<S, Ix3>) -> Array2<f32> {
+    luminance_plane(image)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::ndarray::{s, Array3};
+
+    #[test]
+    fn test_views_and_alpha() {
+        let mut image = Array3::<f32>::zeros((2, 3, 4));
+        image.slice_mut(s![.., .., 2]).fill(1.0);
+        let luminances = luminance_array(&image.view());
+        assert_eq!(luminances.dim(), (2, 3));
+        assert!(luminances.iter().all(|&luminance| luminance == 0.0722));
+        let wide = Array3::<u16>::from_elem((1, 1, 3), 65535);
+        assert_eq!(luminance_array_u16(&wide)[[0, 0]], 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "3 or 4 channels")]
+    fn test_channel_count() {
+        luminance_array(&Array3::<f32>::zeros((1, 1, 2)));
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{Float, LuminanceValue};
+use core::ops::{Add, Div, Mul, Sub};
+
+/// A wrapper that implements [`LuminanceValue`] and [`Float`] for any
+/// [`num_traits::Float`] type.
+///
+/// This can't be a blanket implementation, because it would conflict with the
+/// implementations for [`f32`], [`f64`], and the integer types.
+///
+/// ```
+/// use relative_luminance::{Luminance, NumFloat, Rgb};
+///
+/// let gray: Rgb<NumFloat<f64>> = Rgb::new(NumFloat(0.5), NumFloat(0.5), NumFloat(0.5));
+///
+/// assert!((gray.wcag_relative_luminance().0 - 0.214).abs() < 0.001);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct NumFloat<F>(pub F);
+
+impl<F: num_traits::Float> NumFloat<F> {
+    /// Converts an `f64` constant, like a weight, to `F`.
+    fn convert(value: f64) -> F {
+        F::from(value).expect("f64 constants should be representable as a float")
+    }
+}
+
+impl<F: num_traits::Float> Add for NumFloat<F> {
+    type Output = NumFloat<F>;
+
+    fn add(self, other: Self) -> Self {
+        NumFloat(self.0 + other.0)
+    }
+}
+
+impl<F: num_traits::Float> Sub for NumFloat<F> {
+    type Output = NumFloat<F>;
+
+    fn sub(self, other: Self) -> Self {
+        NumFloat(self.0 - other.0)
+    }
+}
+
+impl<F: num_traits::Float> Mul for NumFloat<F> {
+    type Output = NumFloat<F>;
+
+    fn mul(self, other: Self) -> Self {
+        NumFloat(self.0 * other.0)
+    }
+}
+
+impl<F: num_traits::Float> Div for NumFloat<F> {
+    type Output = NumFloat<F>;
+
+    fn div(self, other: Self) -> Self {
+        NumFloat(self.0 / other.0)
+    }
+}
+
+/// Weights are `f64` constants, because `F` can't be created in a constant.
+impl<F: num_traits::Float> Mul<f64> for NumFloat<F> {
+    type Output = NumFloat<F>;
+
+    fn mul(self, weight: f64) -> Self {
+        NumFloat(self.0 * Self::convert(weight))
+    }
+}
+
+impl<F: num_traits::Float> LuminanceValue for NumFloat<F> {
+    type Channel = NumFloat<F>;
+    type Weight = f64;
+    type Weighted = NumFloat<F>;
+    const RED_WEIGHT: f64 = f64::RED_WEIGHT;
+    const GREEN_WEIGHT: f64 = f64::GREEN_WEIGHT;
+    const BLUE_WEIGHT: f64 = f64::BLUE_WEIGHT;
+}
+
+impl<F: num_traits::Float> Float for NumFloat<F> {
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        NumFloat(Self::convert(value))
+    }
+
+    #[inline]
+    fn powf(self, n: Self) -> Self {
+        NumFloat(self.0.powf(n.0))
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        NumFloat(self.0.abs())
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        NumFloat(self.0.exp())
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        NumFloat(self.0.ln())
+    }
+
+    #[inline]
+    fn floor(self) -> Self {
+        NumFloat(self.0.floor())
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        NumFloat(self.0.sin())
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        NumFloat(self.0.cos())
+    }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        NumFloat(self.0.atan2(other.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Luminance, Rgb};
+
+    #[test]
+    fn test_matches_f32() {
+        let wrapped = Rgb::<NumFloat<f32>>::new(NumFloat(0.2), NumFloat(0.4), NumFloat(0.6));
+        let float = Rgb::<f32>::new(0.2, 0.4, 0.6);
+        assert_eq!(wrapped.relative_luminance().0, float.relative_luminance());
+        assert_eq!(
+            wrapped.wcag_relative_luminance().0,
+            float.wcag_relative_luminance()
+        );
+    }
+}
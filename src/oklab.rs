@@ -0,0 +1,193 @@
+use crate::colorimetry::{multiply, Matrix3};
+use crate::{polar, transfer, Float, Luminance, LuminanceValue, Rgb};
+
+/// The matrix converting linear sRGB to LMS cone responses.
+const SRGB_TO_LMS: Matrix3<f64> = [
+    [0.4122214708, 0.5363325363, 0.0514459929],
+    [0.2119034982, 0.6806995451, 0.1073969566],
+    [0.0883024619, 0.2817188376, 0.6299787005],
+];
+
+/// The matrix converting nonlinear LMS to Oklab.
+const LMS_TO_OKLAB: Matrix3<f64> = [
+    [0.2104542553, 0.7936177850, -0.0040720468],
+    [1.9779984951, -2.4285922050, 0.4505937099],
+    [0.0259040371, 0.7827717662, -0.8086757660],
+];
+
+/// The matrix converting Oklab to nonlinear LMS.
+const OKLAB_TO_LMS: Matrix3<f64> = [
+    [1.0, 0.3963377774, 0.2158037573],
+    [1.0, -0.1055613458, -0.0638541728],
+    [1.0, -0.0894841775, -1.2914855480],
+];
+
+/// The matrix converting LMS cone responses to linear sRGB.
+const LMS_TO_SRGB: Matrix3<f64> = [
+    [4.0767416621, -3.3077115913, 0.2309699292],
+    [-1.2684380046, 2.6097574011, -0.3413193965],
+    [-0.0041960863, -0.7034186147, 1.7076147010],
+];
+
+/// Converts a matrix of constants to any float type.
+fn matrix<F: Float>(matrix: &Matrix3<f64>) -> Matrix3<F> {
+    matrix.map(|row| row.map(F::from_f64))
+}
+
+/// Gets the cube root of a value, which may be negative out of gamut.
+fn cbrt<F: Float>(value: F) -> F {
+    let zero = F::from_f64(0.0);
+    let root = value.abs().powf(F::from_f64(1.0 / 3.0));
+    if value < zero {
+        zero - root
+    } else {
+        root
+    }
+}
+
+/// An [Oklab][oklab] color, with the lightness L in [0.0, 1.0].
+///
+/// Oklab is a perceptual color space like [`Lab`](crate::Lab), but with more uniform
+/// hues. The [`Luminance`] methods convert it to sRGB.
+///
+/// ```
+/// use relative_luminance::{Luminance, Oklab, Rgb};
+///
+/// let white = Oklab::from_linear_rgb(&Rgb::<f64>::new(1.0, 1.0, 1.0));
+///
+/// assert!((white.l - 1.0).abs() < 1e-6);
+/// assert!(white.a.abs() < 1e-6 && white.b.abs() < 1e-6);
+/// assert!((white.wcag_relative_luminance() - 1.0).abs() < 1e-6);
+/// ```
+///
+/// [oklab]: https://bottosson.github.io/posts/oklab/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklab<F> {
+    /// The perceived lightness.
+    pub l: F,
+    /// The green–red axis.
+    pub a: F,
+    /// The blue–yellow axis.
+    pub b: F,
+}
+
+impl<F: Float> Oklab<F> {
+    /// Creates a new `Oklab<F>`.
+    pub fn new(l: F, a: F, b: F) -> Self {
+        Oklab { l, a, b }
+    }
+
+    /// Converts linear sRGB to Oklab.
+    pub fn from_linear_rgb<T>(rgb: &Rgb<T>) -> Self
+    where
+        T: LuminanceValue<Channel = F>,
+    {
+        let lms = multiply(&matrix(&SRGB_TO_LMS), [rgb.r, rgb.g, rgb.b]);
+        let [l, a, b] = multiply(&matrix(&LMS_TO_OKLAB), lms.map(cbrt));
+        Oklab { l, a, b }
+    }
+
+    /// Converts the color to linear sRGB.
+    ///
+    /// Colors outside of the sRGB gamut have channels outside of [0.0, 1.0].
+    pub fn to_linear_rgb<T>(&self) -> Rgb<T>
+    where
+        T: LuminanceValue<Channel = F>,
+    {
+        let lms = multiply(&matrix(&OKLAB_TO_LMS), [self.l, self.a, self.b]);
+        let [r, g, b] = multiply(&matrix(&LMS_TO_SRGB), lms.map(|x| x * x * x));
+        Rgb::new(r, g, b)
+    }
+}
+
+impl<F> Luminance<F> for Oklab<F>
+where
+    F: Float + LuminanceValue<Channel = F>,
+{
+    /// Gets the sRGB-encoded channels of the color.
+    fn luminance_rgb(&self) -> Rgb<F> {
+        self.linear_rgb().encode(&transfer::Srgb)
+    }
+
+    fn linear_rgb(&self) -> Rgb<F> {
+        self.to_linear_rgb()
+    }
+}
+
+/// An Oklab color in cylindrical form, with a lightness, chroma, and hue.
+///
+/// ```
+/// use relative_luminance::Oklch;
+///
+/// let color: Oklch<f64> = Oklch::new(0.7, 0.1, 250.0);
+/// let round_trip = Oklch::from_oklab(&color.to_oklab());
+///
+/// assert!((round_trip.h - color.h).abs() < 1e-9);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklch<F> {
+    /// The perceived lightness.
+    pub l: F,
+    /// The chroma.
+    pub c: F,
+    /// The hue, in degrees.
+    pub h: F,
+}
+
+impl<F: Float> Oklch<F> {
+    /// Creates a new `Oklch<F>`.
+    pub fn new(l: F, c: F, h: F) -> Self {
+        Oklch { l, c, h }
+    }
+
+    /// Converts the color to rectangular form.
+    pub fn to_oklab(&self) -> Oklab<F> {
+        let (a, b) = polar::from_polar(self.c, self.h);
+        Oklab::new(self.l, a, b)
+    }
+
+    /// Converts a color from rectangular form.
+    pub fn from_oklab(oklab: &Oklab<F>) -> Self {
+        let (c, h) = polar::to_polar(oklab.a, oklab.b);
+        Oklch { l: oklab.l, c, h }
+    }
+}
+
+impl<F> Luminance<F> for Oklch<F>
+where
+    F: Float + LuminanceValue<Channel = F>,
+{
+    /// Gets the sRGB-encoded channels of the color.
+    fn luminance_rgb(&self) -> Rgb<F> {
+        self.to_oklab().luminance_rgb()
+    }
+
+    fn linear_rgb(&self) -> Rgb<F> {
+        self.to_oklab().linear_rgb()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for &(r, g, b) in &[(0.2, 0.4, 0.6), (1.0, 0.0, 0.0), (0.0, 0.0, 1.0)] {
+            let rgb = Rgb::<f64>::new(r, g, b);
+            let round_trip: Rgb<f64> = Oklab::from_linear_rgb(&rgb).to_linear_rgb();
+            assert!((round_trip.r - r).abs() < 1e-6);
+            assert!((round_trip.g - g).abs() < 1e-6);
+            assert!((round_trip.b - b).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_red_matches_reference() {
+        // Linear sRGB red is about L 0.628, a 0.225, b 0.126
+        let red = Oklab::from_linear_rgb(&Rgb::<f64>::new(1.0, 0.0, 0.0));
+        assert!((red.l - 0.628).abs() < 0.001);
+        assert!((red.a - 0.225).abs() < 0.001);
+        assert!((red.b - 0.126).abs() < 0.001);
+    }
+}
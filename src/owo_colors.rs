@@ -0,0 +1,27 @@
+use crate::{Luminance, Rgb};
+
+/// Colors from the [`owo-colors`](::owo_colors) crate.
+///
+/// ```
+/// use owo_colors::Rgb;
+/// use relative_luminance::Luminance;
+///
+/// assert_eq!(Rgb(0, 255, 0).relative_luminance(), 0.7152);
+/// ```
+impl Luminance<f32> for ::owo_colors::Rgb {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        Rgb::<u8>::new(self.0, self.1, self.2).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_rgb() {
+        let color = ::owo_colors::Rgb(0x66, 0x33, 0x99);
+        let rgb = Rgb::<u8>::new(0x66, 0x33, 0x99);
+        assert_eq!(color.relative_luminance(), rgb.relative_luminance());
+    }
+}
@@ -0,0 +1,447 @@
+//! Colors packed into integers.
+//!
+//! Many APIs store blue in the lower bits instead of red, like Windows' `COLORREF` and
+//! OpenCV-style BGR pixels. Using the wrong channel order swaps the red and blue
+//! weights, so check which order your pixels use, and use [`PackedBgr`] or a BGR
+//! [`PackedFormat`] for blue-first data.
+//!
+//! ```
+//! use relative_luminance::packed::PackedRgb;
+//! use relative_luminance::Luminance;
+//!
+//! let green = PackedRgb(0x00FF00);
+//!
+//! assert_eq!(green.relative_luminance(), 0.7152);
+//! ```
+use crate::{Luminance, Rgb, Rgba};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+/// Converts an 8-bit channel to the range [0.0, 1.0].
+fn normalize(channel: u8) -> f32 {
+    f32::from(channel) / 255.0
+}
+
+/// A color packed as `0xRRGGBB`, like a hex color code.
+///
+/// The upper 8 bits are ignored.
+///
+/// ```
+/// # use relative_luminance::packed::PackedRgb;
+/// let color = PackedRgb(0x336699);
+///
+/// assert_eq!((color.red(), color.green(), color.blue()), (0x33, 0x66, 0x99));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PackedRgb(pub u32);
+
+impl PackedRgb {
+    /// Gets the red channel.
+    pub const fn red(&self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    /// Gets the green channel.
+    pub const fn green(&self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    /// Gets the blue channel.
+    pub const fn blue(&self) -> u8 {
+        self.0 as u8
+    }
+}
+
+impl Luminance<f32> for PackedRgb {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        Rgb::new(
+            normalize(self.red()),
+            normalize(self.green()),
+            normalize(self.blue()),
+        )
+    }
+}
+
+/// Treats the `u32` as a [`PackedRgb`].
+///
+/// ```
+/// use relative_luminance::Luminance;
+///
+/// assert_eq!(0xFFFFFFu32.relative_luminance(), 1.0);
+/// ```
+#[cfg(feature = "packed-u32")]
+impl Luminance<f32> for u32 {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        PackedRgb(*self).luminance_rgb()
+    }
+}
+
+/// A color packed as `0xBBGGRR`, with blue in the higher bits and red in the lower bits.
+///
+/// The upper 8 bits are ignored.
+///
+/// ```
+/// use relative_luminance::packed::{PackedBgr, PackedRgb};
+/// use relative_luminance::Luminance;
+///
+/// let bgr = PackedBgr(0x996633);
+///
+/// assert_eq!(bgr.red(), 0x33);
+/// assert_eq!(bgr.relative_luminance(), PackedRgb(0x336699).relative_luminance());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PackedBgr(pub u32);
+
+impl PackedBgr {
+    /// Gets the red channel.
+    pub const fn red(&self) -> u8 {
+        self.0 as u8
+    }
+
+    /// Gets the green channel.
+    pub const fn green(&self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    /// Gets the blue channel.
+    pub const fn blue(&self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+}
+
+impl Luminance<f32> for PackedBgr {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        Rgb::new(
+            normalize(self.red()),
+            normalize(self.green()),
+            normalize(self.blue()),
+        )
+    }
+}
+
+/// A 16-bit color with 5 bits of red, 6 bits of green, and 5 bits of blue, as used by
+/// many embedded displays.
+///
+/// The channels are scaled by their own maximum, so that the largest 5-bit and 6-bit
+/// values are both 1.0.
+///
+/// ```
+/// use relative_luminance::packed::Rgb565;
+/// use relative_luminance::Luminance;
+///
+/// let white = Rgb565(0xFFFF);
+/// let green = Rgb565(0x07E0);
+///
+/// assert_eq!(white.relative_luminance(), 1.0);
+/// assert_eq!(green.relative_luminance(), 0.7152);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rgb565(pub u16);
+
+impl Rgb565 {
+    /// Gets the 5-bit red channel.
+    pub const fn red(&self) -> u8 {
+        (self.0 >> 11) as u8 & 0x1F
+    }
+
+    /// Gets the 6-bit green channel.
+    pub const fn green(&self) -> u8 {
+        (self.0 >> 5) as u8 & 0x3F
+    }
+
+    /// Gets the 5-bit blue channel.
+    pub const fn blue(&self) -> u8 {
+        self.0 as u8 & 0x1F
+    }
+}
+
+impl Luminance<f32> for Rgb565 {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        Rgb::new(
+            f32::from(self.red()) / 31.0,
+            f32::from(self.green()) / 63.0,
+            f32::from(self.blue()) / 31.0,
+        )
+    }
+}
+
+/// A 16-bit color with 5 bits for each channel.
+///
+/// The highest bit is ignored.
+///
+/// ```
+/// use relative_luminance::packed::Rgb555;
+/// use relative_luminance::Luminance;
+///
+/// assert_eq!(Rgb555(0x7FFF).relative_luminance(), 1.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rgb555(pub u16);
+
+impl Rgb555 {
+    /// Gets the 5-bit red channel.
+    pub const fn red(&self) -> u8 {
+        (self.0 >> 10) as u8 & 0x1F
+    }
+
+    /// Gets the 5-bit green channel.
+    pub const fn green(&self) -> u8 {
+        (self.0 >> 5) as u8 & 0x1F
+    }
+
+    /// Gets the 5-bit blue channel.
+    pub const fn blue(&self) -> u8 {
+        self.0 as u8 & 0x1F
+    }
+}
+
+impl Luminance<f32> for Rgb555 {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        Rgb::new(
+            f32::from(self.red()) / 31.0,
+            f32::from(self.green()) / 31.0,
+            f32::from(self.blue()) / 31.0,
+        )
+    }
+}
+
+/// The position of each channel in a packed 32-bit pixel.
+///
+/// Formats are named from the most significant byte of the `u32` to the least
+/// significant byte, so [`Argb8888`] has alpha in the highest byte. Pixels read from
+/// bytes in memory should be converted with the matching
+/// [`u32::from_be_bytes`] or [`u32::from_le_bytes`].
+///
+/// ```
+/// use relative_luminance::packed::{Packed, PackedFormat};
+/// use relative_luminance::Luminance;
+///
+/// /// Green in the highest byte, then red, alpha, and blue.
+/// struct Gbar;
+///
+/// impl PackedFormat for Gbar {
+///     const RED_SHIFT: u32 = 16;
+///     const GREEN_SHIFT: u32 = 24;
+///     const BLUE_SHIFT: u32 = 0;
+///     const ALPHA_SHIFT: u32 = 8;
+/// }
+///
+/// let red = Packed::<Gbar>::new(0x00FFFF00);
+///
+/// assert_eq!(red.relative_luminance(), 0.2126);
+/// ```
+pub trait PackedFormat {
+    /// The number of bits to shift the pixel right to get the red channel.
+    const RED_SHIFT: u32;
+    /// The number of bits to shift the pixel right to get the green channel.
+    const GREEN_SHIFT: u32;
+    /// The number of bits to shift the pixel right to get the blue channel.
+    const BLUE_SHIFT: u32;
+    /// The number of bits to shift the pixel right to get the alpha channel.
+    const ALPHA_SHIFT: u32;
+}
+
+/// Red in the highest byte, then green, blue, and alpha.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rgba8888;
+
+/// Alpha in the highest byte, then red, green, and blue.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Argb8888;
+
+/// Blue in the highest byte, then green, red, and alpha.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bgra8888;
+
+/// Alpha in the highest byte, then blue, green, and red.
+///
+/// This is the `u32` of little-endian RGBA bytes, like
+/// `u32::from_le_bytes([r, g, b, a])`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Abgr8888;
+
+macro_rules! impl_packed_format {
+    ($format:ty, $red:literal, $green:literal, $blue:literal, $alpha:literal) => {
+        impl PackedFormat for $format {
+            const RED_SHIFT: u32 = $red;
+            const GREEN_SHIFT: u32 = $green;
+            const BLUE_SHIFT: u32 = $blue;
+            const ALPHA_SHIFT: u32 = $alpha;
+        }
+    };
+}
+
+impl_packed_format!(Rgba8888, 24, 16, 8, 0);
+impl_packed_format!(Argb8888, 16, 8, 0, 24);
+impl_packed_format!(Bgra8888, 8, 16, 24, 0);
+impl_packed_format!(Abgr8888, 0, 8, 16, 24);
+
+/// A 32-bit pixel with 8-bit channels in the order of a [`PackedFormat`].
+///
+/// Like [`Rgba`], the [`Luminance`] methods ignore the alpha channel. Use
+/// [`Packed::to_rgba`] to composite the pixel over a background.
+///
+/// ```
+/// use relative_luminance::packed::{Argb8888, Bgra8888, Packed};
+/// use relative_luminance::Luminance;
+///
+/// let argb = Packed::<Argb8888>::new(0xFF336699);
+/// let bgra = Packed::<Bgra8888>::new(0x996633FF);
+///
+/// assert_eq!(argb.relative_luminance(), bgra.relative_luminance());
+/// assert_eq!(argb.alpha(), 0xFF);
+/// ```
+pub struct Packed<F: PackedFormat> {
+    /// The packed pixel.
+    pub value: u32,
+    format: PhantomData<fn() -> F>,
+}
+
+// These are implemented by hand, since deriving them would also require them for the
+// format, which is only a marker.
+
+impl<F: PackedFormat> Clone for Packed<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: PackedFormat> Copy for Packed<F> {}
+
+impl<F: PackedFormat> fmt::Debug for Packed<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Packed")
+            .field("value", &format_args!("{:#010X}", self.value))
+            .field("format", &core::any::type_name::<F>())
+            .finish()
+    }
+}
+
+impl<F: PackedFormat> Default for Packed<F> {
+    fn default() -> Self {
+        Packed::new(0)
+    }
+}
+
+impl<F: PackedFormat> PartialEq for Packed<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<F: PackedFormat> Eq for Packed<F> {}
+
+impl<F: PackedFormat> Hash for Packed<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<F: PackedFormat> Packed<F> {
+    /// Creates a new `Packed<F>` from a packed pixel.
+    pub const fn new(value: u32) -> Self {
+        Packed {
+            value,
+            format: PhantomData,
+        }
+    }
+
+    /// Gets the red channel.
+    pub const fn red(&self) -> u8 {
+        (self.value >> F::RED_SHIFT) as u8
+    }
+
+    /// Gets the green channel.
+    pub const fn green(&self) -> u8 {
+        (self.value >> F::GREEN_SHIFT) as u8
+    }
+
+    /// Gets the blue channel.
+    pub const fn blue(&self) -> u8 {
+        (self.value >> F::BLUE_SHIFT) as u8
+    }
+
+    /// Gets the alpha channel.
+    pub const fn alpha(&self) -> u8 {
+        (self.value >> F::ALPHA_SHIFT) as u8
+    }
+
+    /// Gets the normalized channels, including alpha.
+    pub fn to_rgba(&self) -> Rgba<f32> {
+        Rgba::new(
+            normalize(self.red()),
+            normalize(self.green()),
+            normalize(self.blue()),
+            normalize(self.alpha()),
+        )
+    }
+}
+
+impl<F: PackedFormat> Luminance<f32> for Packed<F> {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        self.to_rgba().rgb()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ignores_upper_bits() {
+        assert_eq!(
+            PackedRgb(0xFF336699).relative_luminance(),
+            PackedRgb(0x336699).relative_luminance()
+        );
+    }
+
+    #[test]
+    fn test_16_bit_channels() {
+        let color = Rgb565(0x8410);
+        assert_eq!((color.red(), color.green(), color.blue()), (16, 32, 16));
+        let color = Rgb555(0xC104);
+        assert_eq!((color.red(), color.green(), color.blue()), (16, 8, 4));
+        assert_eq!(Rgb555(0x8000).relative_luminance(), 0.0);
+    }
+
+    #[test]
+    fn test_formats_agree() {
+        let rgb = PackedRgb(0x336699).relative_luminance();
+        assert_eq!(
+            Packed::<Rgba8888>::new(0x33669980).relative_luminance(),
+            rgb
+        );
+        assert_eq!(
+            Packed::<Argb8888>::new(0x80336699).relative_luminance(),
+            rgb
+        );
+        assert_eq!(
+            Packed::<Bgra8888>::new(0x99663380).relative_luminance(),
+            rgb
+        );
+        assert_eq!(
+            Packed::<Abgr8888>::new(u32::from_le_bytes([0x33, 0x66, 0x99, 0x80]))
+                .relative_luminance(),
+            rgb
+        );
+    }
+
+    #[test]
+    fn test_traits_without_format_bounds() {
+        struct Gbar;
+
+        impl PackedFormat for Gbar {
+            const RED_SHIFT: u32 = 16;
+            const GREEN_SHIFT: u32 = 24;
+            const BLUE_SHIFT: u32 = 0;
+            const ALPHA_SHIFT: u32 = 8;
+        }
+
+        let packed = Packed::<Gbar>::new(0x99336680);
+        let copy = packed;
+        assert_eq!(packed.clone(), copy);
+        assert_ne!(packed, Packed::default());
+        assert!(format!("{:?}", packed).contains("0x99336680"));
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{Float, Luminance, LuminanceValue, Rgb};
+use ::palette::{LinSrgb, LinSrgba, Srgb, Srgba};
+
+/// sRGB-encoded colors from the [`palette`](::palette) crate.
+impl<T: LuminanceValue<Channel = T>> Luminance<T> for Srgb<T> {
+    fn luminance_rgb(&self) -> Rgb<T> {
+        Rgb::new(self.red, self.green, self.blue)
+    }
+}
+
+/// sRGB-encoded colors from the [`palette`](::palette) crate, ignoring the alpha
+/// channel.
+impl<T: LuminanceValue<Channel = T>> Luminance<T> for Srgba<T> {
+    fn luminance_rgb(&self) -> Rgb<T> {
+        self.color.luminance_rgb()
+    }
+}
+
+/// Linear sRGB colors from the [`palette`](::palette) crate.
+///
+/// The channels are already linear, so they aren't decoded again by
+/// [`Luminance::linear_rgb`].
+impl<T: LuminanceValue<Channel = T>> Luminance<T> for LinSrgb<T> {
+    fn luminance_rgb(&self) -> Rgb<T> {
+        Rgb::new(self.red, self.green, self.blue)
+    }
+
+    fn linear_rgb(&self) -> Rgb<T>
+    where
+        T::Channel: Float,
+    {
+        self.luminance_rgb()
+    }
+}
+
+/// Linear sRGB colors from the [`palette`](::palette) crate, ignoring the alpha
+/// channel.
+impl<T: LuminanceValue<Channel = T>> Luminance<T> for LinSrgba<T> {
+    fn luminance_rgb(&self) -> Rgb<T> {
+        self.color.luminance_rgb()
+    }
+
+    fn linear_rgb(&self) -> Rgb<T>
+    where
+        T::Channel: Float,
+    {
+        self.luminance_rgb()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::palette::IntoColor;
+
+    #[test]
+    fn test_encoded_and_linear_agree() {
+        let encoded = Srgb::new(0.2f64, 0.5, 0.8);
+        let linear: LinSrgb<f64> = encoded.into_linear();
+        assert!(
+            (encoded.wcag_relative_luminance() - linear.wcag_relative_luminance()).abs() < 1e-4
+        );
+        assert!((encoded.linear_rgb().g - linear.green).abs() < 1e-4);
+        let xyz: ::palette::Xyz<::palette::white_point::D65, f64> = linear.into_color();
+        assert!((linear.relative_luminance() - xyz.y).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_alpha_ignored() {
+        let clear = Srgba::new(0u8, 255, 0, 0);
+        assert_eq!(clear.relative_luminance(), 0.7152);
+        let clear = LinSrgba::new(1.0f32, 0.0, 0.0, 0.0);
+        assert_eq!(clear.relative_luminance(), 0.2126);
+    }
+}
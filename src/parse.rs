@@ -0,0 +1,332 @@
+use crate::{Hsl, Rgb, Rgba};
+use core::fmt;
+use core::str::FromStr;
+
+/// An error from parsing a color string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// The string is hex digits that don't start with `#`, like `663399`.
+    MissingHash,
+    /// The string doesn't have 3, 4, 6, or 8 hex digits.
+    InvalidLength,
+    /// The string has a character that isn't a hex digit.
+    InvalidDigit,
+    /// The string has a function other than `rgb()`, `rgba()`, `hsl()`, or `hsla()`.
+    UnknownFunction,
+    /// The string isn't a hex color or a function with a valid list of arguments.
+    InvalidSyntax,
+    /// An argument of a function isn't a valid number, percentage, or angle.
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseColorError::MissingHash => write!(f, "color doesn't start with '#'"),
+            ParseColorError::InvalidLength => write!(f, "color must have 3, 4, 6, or 8 digits"),
+            ParseColorError::InvalidDigit => write!(f, "color has an invalid hex digit"),
+            ParseColorError::UnknownFunction => write!(f, "color has an unknown function"),
+            ParseColorError::InvalidSyntax => write!(f, "color has invalid syntax"),
+            ParseColorError::InvalidNumber => write!(f, "color has an invalid number"),
+        }
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+/// Parses `#RGB`, `#RGBA`, `#RRGGBB`, or `#RRGGBBAA` into bytes, with an opaque alpha
+/// if it's missing.
+fn parse_hex(s: &str) -> Result<[u8; 4], ParseColorError> {
+    let digits = s.strip_prefix('#').ok_or(ParseColorError::MissingHash)?;
+    let digits = digits.as_bytes();
+    let digit = |index: usize| match digits[index] {
+        c @ b'0'..=b'9' => Ok(c - b'0'),
+        c @ b'a'..=b'f' => Ok(c - b'a' + 10),
+        c @ b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(ParseColorError::InvalidDigit),
+    };
+    let mut bytes = [255; 4];
+    match digits.len() {
+        3 | 4 => {
+            for (index, byte) in bytes.iter_mut().take(digits.len()).enumerate() {
+                *byte = digit(index)? * 0x11;
+            }
+        }
+        6 | 8 => {
+            for (index, byte) in bytes.iter_mut().take(digits.len() / 2).enumerate() {
+                *byte = digit(2 * index)? << 4 | digit(2 * index + 1)?;
+            }
+        }
+        _ => return Err(ParseColorError::InvalidLength),
+    }
+    Ok(bytes)
+}
+
+/// Parses a number, rejecting infinities and NaN.
+fn parse_number(s: &str) -> Result<f64, ParseColorError> {
+    match s.parse::<f64>() {
+        Ok(number) if number.is_finite() => Ok(number),
+        _ => Err(ParseColorError::InvalidNumber),
+    }
+}
+
+/// Parses a number, or a percentage of `max`.
+fn parse_percentage(s: &str, max: f64) -> Result<f64, ParseColorError> {
+    match s.strip_suffix('%') {
+        Some(percentage) => Ok(parse_number(percentage)? / 100.0 * max),
+        None => parse_number(s),
+    }
+}
+
+/// Parses a hue, in degrees unless it has a unit.
+fn parse_hue(s: &str) -> Result<f64, ParseColorError> {
+    // "grad" has to be checked before "rad"
+    let units = [
+        ("deg", 1.0),
+        ("grad", 0.9),
+        ("rad", 180.0 / core::f64::consts::PI),
+        ("turn", 360.0),
+    ];
+    for (unit, degrees) in units {
+        if let Some(hue) = s.strip_suffix(unit) {
+            return Ok(parse_number(hue)? * degrees);
+        }
+    }
+    parse_number(s)
+}
+
+/// Splits the arguments of a function into 3 components and an optional alpha, with
+/// either the legacy `r, g, b, a` syntax or the modern `r g b / a` syntax.
+fn split_arguments(arguments: &str) -> Result<([&str; 3], Option<&str>), ParseColorError> {
+    let (components, alpha): (Vec<&str>, _) = if arguments.contains(',') {
+        let mut components: Vec<&str> = arguments.split(',').map(str::trim).collect();
+        let alpha = if components.len() == 4 {
+            components.pop()
+        } else {
+            None
+        };
+        (components, alpha)
+    } else {
+        let (components, alpha) = match arguments.split_once('/') {
+            Some((components, alpha)) => (components, Some(alpha.trim())),
+            None => (arguments, None),
+        };
+        (components.split_whitespace().collect(), alpha)
+    };
+    match components[..] {
+        [a, b, c] if !a.is_empty() && !b.is_empty() && !c.is_empty() => Ok(([a, b, c], alpha)),
+        _ => Err(ParseColorError::InvalidSyntax),
+    }
+}
+
+/// Parses `rgb()`, `rgba()`, `hsl()`, or `hsla()` into channels in [0.0, 1.0].
+fn parse_function(s: &str) -> Result<[f64; 4], ParseColorError> {
+    let (name, arguments) = s.split_once('(').ok_or(ParseColorError::InvalidSyntax)?;
+    let arguments = arguments
+        .strip_suffix(')')
+        .ok_or(ParseColorError::InvalidSyntax)?;
+    let ([first, second, third], alpha) = split_arguments(arguments)?;
+    let alpha = match alpha {
+        Some(alpha) => parse_percentage(alpha, 1.0)?,
+        None => 1.0,
+    };
+    let [r, g, b] = match name.trim().to_ascii_lowercase().as_str() {
+        "rgb" | "rgba" => [
+            parse_percentage(first, 255.0)? / 255.0,
+            parse_percentage(second, 255.0)? / 255.0,
+            parse_percentage(third, 255.0)? / 255.0,
+        ],
+        "hsl" | "hsla" => {
+            let s = parse_percentage(second, 100.0)? / 100.0;
+            let l = parse_percentage(third, 100.0)? / 100.0;
+            let rgb = Hsl::new(parse_hue(first)?, s.clamp(0.0, 1.0), l.clamp(0.0, 1.0)).to_rgb();
+            [rgb.r, rgb.g, rgb.b]
+        }
+        _ => return Err(ParseColorError::UnknownFunction),
+    };
+    Ok([r, g, b, alpha].map(|channel| channel.clamp(0.0, 1.0)))
+}
+
+/// Parses a hex color or a functional notation into channels in [0.0, 1.0].
+fn parse_color(s: &str) -> Result<[f64; 4], ParseColorError> {
+    let s = s.trim();
+    #[cfg(feature = "names")]
+    if let Some(color) = crate::names::lookup(s) {
+        let Rgb { r, g, b } = Rgb::<f64>::from(color.rgb);
+        return Ok([r, g, b, 1.0]);
+    }
+    let is_bare_hex = matches!(s.len(), 3 | 4 | 6 | 8) && s.bytes().all(|c| c.is_ascii_hexdigit());
+    if s.starts_with('#') {
+        Ok(parse_hex(s)?.map(|byte| f64::from(byte) / 255.0))
+    } else if is_bare_hex {
+        Err(ParseColorError::MissingHash)
+    } else {
+        parse_function(s)
+    }
+}
+
+macro_rules! impl_from_str {
+    ($($t:ty => $convert:expr),*) => {
+        $(
+            /// Parses a hex color, like `#663399` or `#639`, or a CSS functional
+            /// notation, like `rgb(102, 51, 153)`, `rgb(40% 20% 60%)`, or
+            /// `hsl(270deg 50% 40%)`.
+            ///
+            /// Colors with alpha, like `#RGBA`, `#RRGGBBAA` and `rgb(0 0 0 / 50%)`,
+            /// are also accepted, but the alpha channel is ignored. Parse an [`Rgba`]
+            /// to keep it. Channels outside of the sRGB gamut are clamped.
+            ///
+            /// With the `names` feature, [named colors](crate::names) like
+            /// `rebeccapurple` are also accepted.
+            ///
+            /// ```
+            /// use relative_luminance::{Luminance, ParseColorError, Rgb};
+            ///
+            /// let green: Rgb<f32> = "#00ff00".parse().unwrap();
+            ///
+            /// assert_eq!(green.relative_luminance(), 0.7152);
+            /// assert_eq!(
+            ///     "00ff00".parse::<Rgb<f32>>().unwrap_err(),
+            ///     ParseColorError::MissingHash
+            /// );
+            ///
+            /// let green: Rgb<f32> = "rgb(0 100% 0)".parse().unwrap();
+            ///
+            /// assert_eq!(green.relative_luminance(), 0.7152);
+            /// ```
+            impl FromStr for Rgb<$t> {
+                type Err = ParseColorError;
+
+                fn from_str(s: &str) -> Result<Self, ParseColorError> {
+                    let [r, g, b, _] = parse_color(s)?.map($convert);
+                    Ok(Rgb::new(r, g, b))
+                }
+            }
+
+            impl TryFrom<&str> for Rgb<$t> {
+                type Error = ParseColorError;
+
+                fn try_from(s: &str) -> Result<Self, ParseColorError> {
+                    s.parse()
+                }
+            }
+
+            /// Parses a hex color, like `#66339980` or `#6398`, or a CSS functional
+            /// notation, like `rgba(102, 51, 153, 0.5)` or `rgb(102 51 153 / 50%)`.
+            ///
+            /// Colors without an alpha channel are opaque.
+            impl FromStr for Rgba<$t> {
+                type Err = ParseColorError;
+
+                fn from_str(s: &str) -> Result<Self, ParseColorError> {
+                    let [r, g, b, a] = parse_color(s)?.map($convert);
+                    Ok(Rgba::new(r, g, b, a))
+                }
+            }
+
+            impl TryFrom<&str> for Rgba<$t> {
+                type Error = ParseColorError;
+
+                fn try_from(s: &str) -> Result<Self, ParseColorError> {
+                    s.parse()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_str!(
+    f32 => |channel| channel as f32,
+    f64 => |channel| channel,
+    u8 => |channel| (channel * 255.0).round() as u8,
+    u16 => |channel| (channel * 65535.0).round() as u16
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_forms() {
+        assert_eq!(parse_hex("#639"), Ok([0x66, 0x33, 0x99, 0xff]));
+        assert_eq!(parse_hex("#6398"), Ok([0x66, 0x33, 0x99, 0x88]));
+        assert_eq!(parse_hex("#663399"), Ok([0x66, 0x33, 0x99, 0xff]));
+        assert_eq!(parse_hex("#6633997F"), Ok([0x66, 0x33, 0x99, 0x7f]));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(parse_hex("663399"), Err(ParseColorError::MissingHash));
+        assert_eq!(parse_hex("#66339"), Err(ParseColorError::InvalidLength));
+        assert_eq!(parse_hex("#66339g"), Err(ParseColorError::InvalidDigit));
+        assert_eq!(parse_hex("#"), Err(ParseColorError::InvalidLength));
+    }
+
+    #[test]
+    fn test_functions() {
+        let purple = [102.0 / 255.0, 51.0 / 255.0, 153.0 / 255.0, 1.0];
+        for color in [
+            "rgb(102, 51, 153)",
+            "rgba(102,51,153,1)",
+            "RGB(102 51 153)",
+            "rgb(40% 20% 60% / 100%)",
+            "hsl(270, 50%, 40%)",
+            "hsla(270deg 50% 40% / 1)",
+            "hsl(0.75turn 50 40)",
+        ] {
+            let parsed = parse_color(color).unwrap();
+            for (parsed, expected) in parsed.iter().zip(purple) {
+                assert!((parsed - expected).abs() < 1e-9, "{color}");
+            }
+        }
+        assert_eq!(parse_color(" rgb(0 0 0 / 0.5) ").unwrap()[3], 0.5);
+        assert_eq!(
+            parse_color("rgb(300, -5, 0)").unwrap(),
+            [1.0, 0.0, 0.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn test_function_errors() {
+        let error = |color| parse_color(color).unwrap_err();
+        assert_eq!(error("rgb"), ParseColorError::InvalidSyntax);
+        assert_eq!(error("rebeccapurple2"), ParseColorError::InvalidSyntax);
+        assert_eq!(error("lab(50 0 0)"), ParseColorError::UnknownFunction);
+        assert_eq!(error("rgb(0 0 0"), ParseColorError::InvalidSyntax);
+        assert_eq!(error("rgb(0, 0)"), ParseColorError::InvalidSyntax);
+        assert_eq!(error("rgb(0, , 0)"), ParseColorError::InvalidSyntax);
+        assert_eq!(error("rgb(0 0 0 0)"), ParseColorError::InvalidSyntax);
+        assert_eq!(error("rgb(0 0 red)"), ParseColorError::InvalidNumber);
+        assert_eq!(error("hsl(nan 0% 0%)"), ParseColorError::InvalidNumber);
+    }
+
+    #[test]
+    fn test_parse_rgb() {
+        let green: Rgb<f64> = "#00FF00".parse().unwrap();
+        assert_eq!(green.relative_luminance(), 0.7152);
+        let clear = Rgba::<f32>::try_from("#00f0").unwrap();
+        assert_eq!((clear.b, clear.a), (1.0, 0.0));
+        let purple: Rgb<u8> = "#663399".parse().unwrap();
+        assert_eq!((purple.r, purple.g, purple.b), (0x66, 0x33, 0x99));
+        let gray: Rgba<u16> = "rgb(50% 50% 50% / 0)".parse().unwrap();
+        assert_eq!((gray.g, gray.a), (32768, 0));
+    }
+
+    #[test]
+    fn test_parse_missing_hash() {
+        for color in ["663399", "639", "6398", "6633997F"] {
+            assert_eq!(
+                color.parse::<Rgb<f32>>().unwrap_err(),
+                ParseColorError::MissingHash
+            );
+        }
+        assert_eq!(
+            Rgba::<u8>::try_from(" 00f0 ").unwrap_err(),
+            ParseColorError::MissingHash
+        );
+        assert_eq!(
+            "66339".parse::<Rgb<f32>>().unwrap_err(),
+            ParseColorError::InvalidSyntax
+        );
+    }
+}
@@ -0,0 +1,122 @@
+//! Conversions and searches shared by the cylindrical forms of [`Lab`](crate::Lab)
+//! and [`Oklab`](crate::Oklab), and by the lightness searches built on them.
+use crate::lab::d65;
+use crate::{colorimetry, Float, Lch, LuminanceValue, Oklch, Rgb};
+
+/// Converts the a and b axes of a color to its chroma and hue, in degrees in
+/// [0.0, 360.0).
+pub(crate) fn to_polar<F: Float>(a: F, b: F) -> (F, F) {
+    let f = F::from_f64;
+    let c = (a * a + b * b).powf(f(0.5));
+    let h = b.atan2(a) * f(180.0 / core::f64::consts::PI);
+    let h = if h < f(0.0) { h + f(360.0) } else { h };
+    (c, h)
+}
+
+/// Converts the chroma and hue, in degrees, of a color to its a and b axes.
+pub(crate) fn from_polar<F: Float>(c: F, h: F) -> (F, F) {
+    let radians = h * F::from_f64(core::f64::consts::PI / 180.0);
+    (c * radians.cos(), c * radians.sin())
+}
+
+/// Reduces the chroma of a color until its linear sRGB channels, from `to_linear`, are
+/// in gamut, and gets the clamped channels and the chroma.
+fn gamut_map<F: Float>(chroma: F, to_linear: impl Fn(F) -> [F; 3]) -> ([F; 3], F) {
+    let f = F::from_f64;
+    let tolerance = f(1e-6);
+    let in_gamut = |chroma| {
+        to_linear(chroma)
+            .iter()
+            .all(|&channel| channel >= f(0.0) - tolerance && channel <= f(1.0) + tolerance)
+    };
+    let chroma = search(f(0.0), chroma, in_gamut).unwrap_or(f(0.0));
+    (to_linear(chroma).map(clamp_unit), chroma)
+}
+
+/// Reduces the chroma of a color until it's in the sRGB gamut, and gets its linear
+/// channels and chroma.
+pub(crate) fn gamut_map_oklch<F>(color: Oklch<F>) -> (Rgb<F>, F)
+where
+    F: Float + LuminanceValue<Channel = F>,
+{
+    let to_linear = |chroma| {
+        let Rgb { r, g, b } = Oklch::new(color.l, chroma, color.h)
+            .to_oklab()
+            .to_linear_rgb::<F>();
+        [r, g, b]
+    };
+    let ([r, g, b], chroma) = gamut_map(color.c, to_linear);
+    (Rgb::new(r, g, b), chroma)
+}
+
+/// Reduces the chroma of a color until it's in the sRGB gamut, and gets its linear
+/// channels.
+pub(crate) fn gamut_map_lch<F: Float>(color: Lch<F>) -> [F; 3] {
+    let to_linear = |chroma| {
+        let lab = Lch::new(color.l, chroma, color.h).to_lab();
+        colorimetry::xyz_to_srgb(lab.to_xyz(d65()))
+    };
+    gamut_map(color.c, to_linear).0
+}
+
+/// The number of halvings of a search range, which is enough for any float type up
+/// to `f64`.
+const SEARCH_STEPS: usize = 48;
+
+/// Finds the value between `start` and `end` that is closest to `end` and still
+/// passes a check, if the check passes at `start` and stops passing at some point
+/// toward `end`.
+pub(crate) fn search<F: Float>(start: F, end: F, passes: impl Fn(F) -> bool) -> Option<F> {
+    if !passes(start) {
+        return None;
+    }
+    if passes(end) {
+        return Some(end);
+    }
+    let (mut pass, mut fail) = (start, end);
+    for _ in 0..SEARCH_STEPS {
+        let middle = (pass + fail) / F::from_f64(2.0);
+        if passes(middle) {
+            pass = middle;
+        } else {
+            fail = middle;
+        }
+    }
+    Some(pass)
+}
+
+/// Clamps a value to [0.0, 1.0].
+pub(crate) fn clamp_unit<F: Float>(value: F) -> F {
+    let (zero, one) = (F::from_f64(0.0), F::from_f64(1.0));
+    if value < zero {
+        zero
+    } else if value > one {
+        one
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search() {
+        let threshold = search(0.0f64, 1.0, |value| value < 0.3).unwrap();
+        assert!((threshold - 0.3).abs() < 1e-9);
+        assert_eq!(search(0.0f64, 1.0, |_| true), Some(1.0));
+        assert_eq!(search(0.0f64, 1.0, |value| value > 0.5), None);
+    }
+
+    #[test]
+    fn test_gamut_map_oklch_keeps_in_gamut_colors() {
+        let gray = Oklch::<f64>::new(0.5, 0.0, 0.0);
+        assert_eq!(gamut_map_oklch(gray).1, 0.0);
+        let (Rgb { r, g, b }, chroma) = gamut_map_oklch(Oklch::<f64>::new(0.7, 0.4, 150.0));
+        assert!(chroma < 0.4);
+        assert!([r, g, b]
+            .iter()
+            .all(|channel| (0.0..=1.0).contains(channel)));
+    }
+}
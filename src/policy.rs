@@ -0,0 +1,242 @@
+//! A shared accessibility policy, which can be stored in a config file with the
+//! `serde` feature.
+//!
+//! ```
+//! use relative_luminance::policy::Policy;
+//! use relative_luminance::theme::Theme;
+//! use relative_luminance::wcag::TextSize;
+//! use relative_luminance::Rgb;
+//!
+//! let policy = Policy::<f64>::default();
+//! let gray: Rgb<f64> = Rgb::new(0.5, 0.5, 0.5);
+//! let white: Rgb<f64> = Rgb::new(1.0, 1.0, 1.0);
+//!
+//! assert!(!policy.check(&gray, &white, TextSize::Normal));
+//! assert!(policy.check(&gray, &white, TextSize::Large));
+//! assert_eq!(policy.theme(&gray), Theme::Light);
+//! ```
+use crate::contrast::Requirement;
+use crate::lightness::{self, Bands, Brightness};
+use crate::standard::{AcesCg, AdobeRgb, Bt2020, Bt601, Bt709, DisplayP3, ProPhotoRgb};
+use crate::theme::{Theme, MIDDLE_LUMINANCE};
+use crate::transfer::{self, Gamma, Hlg, Linear, Pq, TransferFunction};
+use crate::wcag::TextSize;
+use crate::{Float, Luminance, LuminanceValue, Weights};
+
+/// A [`Standard`](crate::Standard) that can be chosen at runtime.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum StandardName {
+    /// [`Bt709`], shared by sRGB.
+    #[default]
+    Bt709,
+    /// [`Bt601`].
+    Bt601,
+    /// [`Bt2020`].
+    Bt2020,
+    /// [`DisplayP3`].
+    DisplayP3,
+    /// [`AdobeRgb`].
+    AdobeRgb,
+    /// [`ProPhotoRgb`].
+    ProPhotoRgb,
+    /// [`AcesCg`].
+    AcesCg,
+}
+
+impl StandardName {
+    /// Gets the weights of the standard.
+    pub fn weights<F>(&self) -> Weights<F>
+    where
+        F: Float + LuminanceValue<Weight = F>,
+    {
+        let weights: Weights<f64> = match self {
+            StandardName::Bt709 => Weights::from_standard::<Bt709>(),
+            StandardName::Bt601 => Weights::from_standard::<Bt601>(),
+            StandardName::Bt2020 => Weights::from_standard::<Bt2020>(),
+            StandardName::DisplayP3 => Weights::from_standard::<DisplayP3>(),
+            StandardName::AdobeRgb => Weights::from_standard::<AdobeRgb>(),
+            StandardName::ProPhotoRgb => Weights::from_standard::<ProPhotoRgb>(),
+            StandardName::AcesCg => Weights::from_standard::<AcesCg>(),
+        };
+        let f = F::from_f64;
+        Weights::new(f(weights.r), f(weights.g), f(weights.b))
+    }
+}
+
+/// A [`TransferFunction`] that can be chosen at runtime.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum TransferName {
+    /// [`Linear`].
+    Linear,
+    /// [`transfer::Srgb`].
+    #[default]
+    Srgb,
+    /// [`Gamma`], with its exponent.
+    Gamma(f64),
+    /// [`ProPhotoRgb`].
+    ProPhotoRgb,
+    /// [`Pq`].
+    Pq,
+    /// [`Hlg`].
+    Hlg,
+}
+
+impl TransferFunction for TransferName {
+    fn decode<F: Float>(&self, encoded: F) -> F {
+        match *self {
+            TransferName::Linear => Linear.decode(encoded),
+            TransferName::Srgb => transfer::Srgb.decode(encoded),
+            TransferName::Gamma(gamma) => Gamma(gamma).decode(encoded),
+            TransferName::ProPhotoRgb => ProPhotoRgb.decode(encoded),
+            TransferName::Pq => Pq.decode(encoded),
+            TransferName::Hlg => Hlg.decode(encoded),
+        }
+    }
+
+    fn encode<F: Float>(&self, linear: F) -> F {
+        match *self {
+            TransferName::Linear => Linear.encode(linear),
+            TransferName::Srgb => transfer::Srgb.encode(linear),
+            TransferName::Gamma(gamma) => Gamma(gamma).encode(linear),
+            TransferName::ProPhotoRgb => ProPhotoRgb.encode(linear),
+            TransferName::Pq => Pq.encode(linear),
+            TransferName::Hlg => Hlg.encode(linear),
+        }
+    }
+}
+
+/// The contrast requirements, thresholds, and color space of an accessibility policy.
+///
+/// The contrast requirements always use the definitions of WCAG and APCA, which have
+/// their own weights and transfer functions. The [`standard`](Policy::standard) and
+/// [`transfer`](Policy::transfer) are used by the other checks, which get the
+/// luminance with [`Policy::relative_luminance`].
+///
+/// With the `serde` feature, missing fields are deserialized with their defaults.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(default)
+)]
+pub struct Policy<F> {
+    /// The requirement for normal text, a WCAG ratio of 4.5 by default.
+    pub normal_text: Requirement<F>,
+    /// The requirement for large text, a WCAG ratio of 3.0 by default.
+    pub large_text: Requirement<F>,
+    /// The luminance above which a background is light, [`MIDDLE_LUMINANCE`] by
+    /// default.
+    pub theme_threshold: F,
+    /// The bands of [`Policy::classify`].
+    pub bands: Bands<F>,
+    /// The weights of [`Policy::relative_luminance`].
+    pub standard: StandardName,
+    /// The transfer function of [`Policy::relative_luminance`].
+    pub transfer: TransferName,
+}
+
+impl<F: Float> Default for Policy<F> {
+    fn default() -> Self {
+        let f = F::from_f64;
+        Policy {
+            normal_text: Requirement::Wcag(f(4.5)),
+            large_text: Requirement::Wcag(f(3.0)),
+            theme_threshold: f(MIDDLE_LUMINANCE),
+            bands: Bands::default(),
+            standard: StandardName::default(),
+            transfer: TransferName::default(),
+        }
+    }
+}
+
+impl<F> Policy<F>
+where
+    F: Float + LuminanceValue<Channel = F, Weight = F, Weighted = F>,
+{
+    /// Checks if text of a size has the required contrast on a background.
+    pub fn check<Text, Background>(
+        &self,
+        text: &Text,
+        background: &Background,
+        text_size: TextSize,
+    ) -> bool
+    where
+        Text: Luminance<F> + ?Sized,
+        Background: Luminance<F> + ?Sized,
+    {
+        let requirement = match text_size {
+            TextSize::Normal => self.normal_text,
+            TextSize::Large => self.large_text,
+        };
+        requirement.is_met(text, background)
+    }
+
+    /// Gets the relative luminance of a color, by decoding its
+    /// [`Luminance::luminance_rgb`] with the transfer function and weighting it with the
+    /// standard.
+    pub fn relative_luminance<C: Luminance<F> + ?Sized>(&self, color: &C) -> F {
+        color
+            .luminance_rgb()
+            .decode(&self.transfer)
+            .relative_luminance_with(&self.standard.weights())
+    }
+
+    /// Gets the [`Theme`] of a background color, by comparing its relative luminance to
+    /// the threshold.
+    pub fn theme<C: Luminance<F> + ?Sized>(&self, color: &C) -> Theme {
+        if self.relative_luminance(color) > self.theme_threshold {
+            Theme::Light
+        } else {
+            Theme::Dark
+        }
+    }
+
+    /// Gets the [`Brightness`] of a color, by classifying the L\* of its relative
+    /// luminance into the bands.
+    pub fn classify<C: Luminance<F> + ?Sized>(&self, color: &C) -> Brightness {
+        self.bands
+            .classify(lightness::y_to_lstar(self.relative_luminance(color)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rgb;
+
+    #[test]
+    fn test_default_matches_wcag() {
+        let policy = Policy::<f64>::default();
+        let color = Rgb::<f64>::new(0.2, 0.5, 0.8);
+        assert_eq!(
+            policy.relative_luminance(&color),
+            color.wcag_relative_luminance()
+        );
+        assert_eq!(policy.classify(&color), color.classify(&Bands::default()));
+    }
+
+    #[test]
+    fn test_standard_and_transfer() {
+        let policy = Policy::<f32> {
+            standard: StandardName::Bt601,
+            transfer: TransferName::Linear,
+            ..Policy::default()
+        };
+        let red = Rgb::<f32>::new(1.0, 0.0, 0.0);
+        assert_eq!(policy.relative_luminance(&red), 0.299);
+        assert_eq!(
+            StandardName::AcesCg.weights::<f64>().r,
+            <AcesCg as crate::Standard<f64>>::RED_WEIGHT
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use crate::LuminanceValue;
+use core::ops::{Add, Mul, Sub};
+
+/// A signed fixed-point number with 16 integer bits and 16 fractional bits.
+///
+/// This is a [`LuminanceValue`] that only needs integer math, for targets without a
+/// floating-point unit.
+///
+/// ```
+/// use relative_luminance::{Luminance, LuminanceValue, Q16_16, Rgb};
+///
+/// // Normalizing 8-bit channels without floats
+/// let channel = |value| Q16_16::from_ratio(value, 255);
+/// let green: Rgb<Q16_16> = Rgb::new(channel(0), channel(255), channel(0));
+/// let white: Rgb<Q16_16> = Rgb::new(channel(255), channel(255), channel(255));
+///
+/// assert_eq!(green.relative_luminance(), Q16_16::GREEN_WEIGHT);
+/// assert_eq!(white.relative_luminance(), Q16_16::ONE);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Q16_16(i32);
+
+impl Q16_16 {
+    /// The number of fractional bits.
+    const FRACTIONAL_BITS: u32 = 16;
+
+    /// 0.0
+    pub const ZERO: Q16_16 = Q16_16(0);
+    /// 1.0
+    pub const ONE: Q16_16 = Q16_16(1 << Self::FRACTIONAL_BITS);
+
+    /// Creates a number from its raw bits, where 65536 is 1.0.
+    pub const fn from_bits(bits: i32) -> Self {
+        Q16_16(bits)
+    }
+
+    /// Gets the raw bits of the number, where 65536 is 1.0.
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    /// Creates the nearest number to `numerator / denominator`, like 128 / 255 for an
+    /// 8-bit channel.
+    ///
+    /// Quotients outside of the range of Q16.16, about ±32768, saturate to the largest
+    /// or smallest number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is 0.
+    pub const fn from_ratio(numerator: i32, denominator: i32) -> Self {
+        let scaled = (numerator as i64) << Self::FRACTIONAL_BITS;
+        let half = (denominator as i64).abs() / 2;
+        let rounded = if (scaled < 0) == (denominator < 0) {
+            scaled + half
+        } else {
+            scaled - half
+        };
+        let quotient = rounded / denominator as i64;
+        if quotient > i32::MAX as i64 {
+            Q16_16(i32::MAX)
+        } else if quotient < i32::MIN as i64 {
+            Q16_16(i32::MIN)
+        } else {
+            Q16_16(quotient as i32)
+        }
+    }
+}
+
+impl Add for Q16_16 {
+    type Output = Q16_16;
+
+    fn add(self, other: Q16_16) -> Q16_16 {
+        Q16_16(self.0 + other.0)
+    }
+}
+
+impl Sub for Q16_16 {
+    type Output = Q16_16;
+
+    fn sub(self, other: Q16_16) -> Q16_16 {
+        Q16_16(self.0 - other.0)
+    }
+}
+
+impl Mul for Q16_16 {
+    type Output = Q16_16;
+
+    fn mul(self, other: Q16_16) -> Q16_16 {
+        let product = self.0 as i64 * other.0 as i64;
+        let half = 1 << (Self::FRACTIONAL_BITS - 1);
+        Q16_16(((product + half) >> Self::FRACTIONAL_BITS) as i32)
+    }
+}
+
+impl LuminanceValue for Q16_16 {
+    type Channel = Q16_16;
+    type Weight = Q16_16;
+    type Weighted = Q16_16;
+    // The nearest values to 0.2126, 0.7152, and 0.0722, which also sum to 1.0
+    const RED_WEIGHT: Q16_16 = Q16_16(13933);
+    const GREEN_WEIGHT: Q16_16 = Q16_16(46871);
+    const BLUE_WEIGHT: Q16_16 = Q16_16(4732);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_ratio_rounds() {
+        assert_eq!(Q16_16::from_ratio(1, 3).to_bits(), 21845);
+        assert_eq!(Q16_16::from_ratio(2, 3).to_bits(), 43691);
+        assert_eq!(Q16_16::from_ratio(-2, 3).to_bits(), -43691);
+        assert_eq!(Q16_16::from_ratio(255, 255), Q16_16::ONE);
+    }
+
+    #[test]
+    fn test_from_ratio_saturates() {
+        assert_eq!(Q16_16::from_ratio(i32::MAX, 1).to_bits(), i32::MAX);
+        assert_eq!(Q16_16::from_ratio(i32::MIN, 1).to_bits(), i32::MIN);
+        assert_eq!(Q16_16::from_ratio(1, -1).to_bits(), -65536);
+        assert_eq!(Q16_16::from_ratio(32768, -1).to_bits(), i32::MIN);
+    }
+
+    #[test]
+    fn test_weights_sum_to_one() {
+        let sum = Q16_16::RED_WEIGHT + Q16_16::GREEN_WEIGHT + Q16_16::BLUE_WEIGHT;
+        assert_eq!(sum, Q16_16::ONE);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{ansi, Luminance, Rgb};
+use ::ratatui::style::Color;
+
+/// Colors from the [`ratatui`](::ratatui) crate.
+///
+/// Named and indexed colors are resolved with the default [`ansi`] palette. The
+/// terminal's own default color ([`Color::Reset`]) can't be known, so it's treated as
+/// black.
+///
+/// ```
+/// use ratatui::style::Color;
+/// use relative_luminance::Luminance;
+///
+/// assert_eq!(Color::Rgb(0, 255, 0).relative_luminance(), 0.7152);
+/// assert_eq!(Color::White.relative_luminance(), 1.0);
+/// assert_eq!(Color::Indexed(15).relative_luminance(), 1.0);
+/// ```
+impl Luminance<f32> for Color {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        let rgb = match *self {
+            Color::Rgb(r, g, b) => Rgb::new(r, g, b),
+            Color::Indexed(index) => ansi::indexed(index),
+            Color::Reset | Color::Black => ansi::indexed(0),
+            Color::Red => ansi::indexed(1),
+            Color::Green => ansi::indexed(2),
+            Color::Yellow => ansi::indexed(3),
+            Color::Blue => ansi::indexed(4),
+            Color::Magenta => ansi::indexed(5),
+            Color::Cyan => ansi::indexed(6),
+            Color::Gray => ansi::indexed(7),
+            Color::DarkGray => ansi::indexed(8),
+            Color::LightRed => ansi::indexed(9),
+            Color::LightGreen => ansi::indexed(10),
+            Color::LightYellow => ansi::indexed(11),
+            Color::LightBlue => ansi::indexed(12),
+            Color::LightMagenta => ansi::indexed(13),
+            Color::LightCyan => ansi::indexed(14),
+            Color::White => ansi::indexed(15),
+        };
+        rgb.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_match_indexed() {
+        let named = [Color::Black, Color::Blue, Color::Gray, Color::LightCyan];
+        for (color, index) in named.into_iter().zip([0, 4, 7, 14]) {
+            assert_eq!(
+                color.relative_luminance(),
+                Color::Indexed(index).relative_luminance()
+            );
+        }
+    }
+}
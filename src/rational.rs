@@ -0,0 +1,42 @@
+use crate::LuminanceValue;
+use num_rational::Ratio;
+
+macro_rules! impl_ratio {
+    ($($t:ty),*) => {
+        $(
+            /// Exact rational channels from the [`num-rational`](num_rational) crate.
+            ///
+            /// The weights are the exact fractions 2126/10000, 7152/10000, and
+            /// 722/10000. `BigRational` isn't supported, because it isn't `Copy`.
+            impl LuminanceValue for Ratio<$t> {
+                type Channel = Ratio<$t>;
+                type Weight = Ratio<$t>;
+                type Weighted = Ratio<$t>;
+                // The reduced forms of 2126/10000, 7152/10000, and 722/10000
+                const RED_WEIGHT: Ratio<$t> = Ratio::new_raw(1063, 5000);
+                const GREEN_WEIGHT: Ratio<$t> = Ratio::new_raw(447, 625);
+                const BLUE_WEIGHT: Ratio<$t> = Ratio::new_raw(361, 5000);
+            }
+        )*
+    };
+}
+
+impl_ratio!(i32, i64, i128);
+
+#[cfg(test)]
+mod tests {
+    use crate::Rgb;
+    use num_rational::Ratio;
+
+    #[test]
+    fn test_exact_luminance() {
+        let gray = Ratio::new(1, 2);
+        let zero = Ratio::new(0, 1);
+        let color = Rgb::<Ratio<i64>>::new(gray, gray, zero);
+        assert_eq!(color.relative_luminance(), Ratio::new(9278, 20000));
+
+        let one = Ratio::new(1, 1);
+        let white = Rgb::<Ratio<i64>>::new(one, one, one);
+        assert_eq!(white.relative_luminance(), one);
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{Luminance, LuminanceValue, Rgb};
+
+macro_rules! impl_rgb {
+    ($($pixel:ident),*) => {
+        $(
+            /// Pixels from the [`rgb`](::rgb) crate, like `RGB8` and `RGB16`.
+            ///
+            /// Any alpha channel is ignored.
+            impl<T: LuminanceValue<Channel = T>> Luminance<T> for ::rgb::$pixel<T> {
+                fn luminance_rgb(&self) -> Rgb<T> {
+                    Rgb::new(self.r, self.g, self.b)
+                }
+            }
+        )*
+    };
+}
+
+impl_rgb!(Rgb, Rgba, Bgr, Bgra, Argb, Abgr);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rgb::{Bgr, RGB16, RGB8, RGBA8};
+
+    #[test]
+    fn test_pixels() {
+        assert_eq!(RGB8::new(0, 255, 0).relative_luminance(), 0.7152);
+        assert_eq!(RGB16::new(65535, 0, 0).relative_luminance(), 0.2126);
+        assert_eq!(RGBA8::new(0, 0, 255, 0).relative_luminance(), 0.0722);
+        let bgr = Bgr {
+            b: 1.0f32,
+            g: 0.0,
+            r: 0.0,
+        };
+        assert_eq!(bgr.relative_luminance(), 0.0722);
+    }
+}
@@ -0,0 +1,316 @@
+use crate::{Float, Luminance, LuminanceValue, Rgb};
+
+/// RGB channels with a straight (not premultiplied) alpha channel.
+///
+/// The luminance of a transparent color depends on what's behind it, so
+/// [`Rgba::composited_luminance`] should usually be used instead of the [`Luminance`]
+/// methods, which ignore the alpha channel and treat the color as opaque.
+///
+/// ```
+/// use relative_luminance::{Luminance, Rgb, Rgba};
+///
+/// // A translucent black overlay
+/// let overlay: Rgba<f32> = Rgba::new(0.0, 0.0, 0.0, 0.25);
+/// let white: Rgb<f32> = Rgb::new(1.0, 1.0, 1.0);
+///
+/// assert_eq!(overlay.relative_luminance(), 0.0);
+/// assert_eq!(overlay.composited_luminance(&white), 0.75);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        ::zerocopy::FromBytes,
+        ::zerocopy::IntoBytes,
+        ::zerocopy::Immutable,
+        ::zerocopy::KnownLayout
+    )
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize),
+    serde(bound(serialize = "T::Channel: ::serde::Serialize"))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize)
+)]
+pub struct Rgba<T: LuminanceValue> {
+    pub r: T::Channel,
+    pub g: T::Channel,
+    pub b: T::Channel,
+    /// The opacity of the color, in the range [0.0, 1.0].
+    pub a: T::Channel,
+}
+
+impl<T: LuminanceValue> Rgba<T> {
+    /// Creates a new `Rgba<T>`
+    pub fn new(r: T::Channel, g: T::Channel, b: T::Channel, a: T::Channel) -> Self {
+        Rgba { r, g, b, a }
+    }
+
+    /// Gets the color channels without the alpha channel.
+    pub fn rgb(&self) -> Rgb<T> {
+        Rgb::new(self.r, self.g, self.b)
+    }
+
+    /// Blends this color over an opaque background with the "source over" operator.
+    ///
+    /// Like web browsers, this blends the channels as they are, without decoding them
+    /// to linear light.
+    ///
+    /// ```
+    /// use relative_luminance::{Rgb, Rgba};
+    ///
+    /// let red: Rgba<f32> = Rgba::new(1.0, 0.0, 0.0, 0.5);
+    /// let blue: Rgb<f32> = Rgb::new(0.0, 0.0, 1.0);
+    /// let purple = red.composite_over(&blue);
+    ///
+    /// assert_eq!((purple.r, purple.g, purple.b), (0.5, 0.0, 0.5));
+    /// ```
+    pub fn composite_over(&self, background: &Rgb<T>) -> Rgb<T>
+    where
+        T::Channel: Float,
+    {
+        let alpha = self.a;
+        let coverage = T::Channel::from_f64(1.0) - alpha;
+        Rgb {
+            r: self.r * alpha + background.r * coverage,
+            g: self.g * alpha + background.g * coverage,
+            b: self.b * alpha + background.b * coverage,
+        }
+    }
+
+    /// Gets the relative luminance of this color blended over an opaque background.
+    ///
+    /// Like [`Luminance::relative_luminance`], this weighs the blended channels without
+    /// decoding them, so it isn't the luminance that the WCAG contrast checks use. Use
+    /// [`Rgba::composited_wcag_relative_luminance`] for sRGB colors. See
+    /// [`Rgba::composite_over`].
+    pub fn composited_luminance(&self, background: &Rgb<T>) -> T::Weighted
+    where
+        T::Channel: Float,
+    {
+        self.composite_over(background).relative_luminance()
+    }
+
+    /// Gets the [WCAG relative luminance](Luminance::wcag_relative_luminance) of this
+    /// color blended over an opaque background.
+    ///
+    /// The channels are blended as they are, like by [`Rgba::composite_over`], and
+    /// then decoded with the sRGB transfer function.
+    ///
+    /// ```
+    /// use relative_luminance::{Luminance, Rgb, Rgba};
+    ///
+    /// let overlay: Rgba<f64> = Rgba::new(0.0, 0.0, 0.0, 0.5);
+    /// let white: Rgb<f64> = Rgb::new(1.0, 1.0, 1.0);
+    /// let blended: Rgb<f64> = Rgb::new(0.5, 0.5, 0.5);
+    ///
+    /// assert_eq!(overlay.composited_luminance(&white), 0.5);
+    /// assert_eq!(
+    ///     overlay.composited_wcag_relative_luminance(&white),
+    ///     blended.wcag_relative_luminance()
+    /// );
+    /// ```
+    pub fn composited_wcag_relative_luminance(&self, background: &Rgb<T>) -> T::Weighted
+    where
+        T::Channel: Float,
+    {
+        self.composite_over(background).wcag_relative_luminance()
+    }
+
+    /// Multiplies the color channels by the alpha channel.
+    ///
+    /// ```
+    /// use relative_luminance::Rgba;
+    ///
+    /// let red: Rgba<f32> = Rgba::new(1.0, 0.0, 0.0, 0.5);
+    ///
+    /// assert_eq!(red.premultiply().r, 0.5);
+    /// ```
+    pub fn premultiply(&self) -> PremultipliedRgba<T>
+    where
+        T::Channel: Float,
+    {
+        PremultipliedRgba {
+            r: self.r * self.a,
+            g: self.g * self.a,
+            b: self.b * self.a,
+            a: self.a,
+        }
+    }
+}
+
+impl<T: LuminanceValue> Luminance<T> for Rgba<T> {
+    /// Gets the color channels, ignoring the alpha channel.
+    fn luminance_rgb(&self) -> Rgb<T> {
+        self.rgb()
+    }
+}
+
+/// RGB channels that have already been multiplied by their alpha channel, like the
+/// pixels of many GPU framebuffers.
+///
+/// The [`Luminance`] methods un-premultiply the channels first, so they give the
+/// luminance of the opaque color rather than a darker one. Like with [`Rgba`],
+/// [`PremultipliedRgba::composited_luminance`] accounts for the background.
+///
+/// ```
+/// use relative_luminance::{Luminance, PremultipliedRgba, Rgb};
+///
+/// // Half-transparent white
+/// let overlay: PremultipliedRgba<f32> = PremultipliedRgba::new(0.5, 0.5, 0.5, 0.5);
+/// let black: Rgb<f32> = Rgb::new(0.0, 0.0, 0.0);
+///
+/// assert_eq!(overlay.relative_luminance(), 1.0);
+/// assert_eq!(overlay.composited_luminance(&black), 0.5);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        ::zerocopy::FromBytes,
+        ::zerocopy::IntoBytes,
+        ::zerocopy::Immutable,
+        ::zerocopy::KnownLayout
+    )
+)]
+pub struct PremultipliedRgba<T: LuminanceValue> {
+    pub r: T::Channel,
+    pub g: T::Channel,
+    pub b: T::Channel,
+    /// The opacity of the color, in the range [0.0, 1.0].
+    pub a: T::Channel,
+}
+
+impl<T: LuminanceValue> PremultipliedRgba<T> {
+    /// Creates a new `PremultipliedRgba<T>` from channels that are already
+    /// premultiplied.
+    pub fn new(r: T::Channel, g: T::Channel, b: T::Channel, a: T::Channel) -> Self {
+        PremultipliedRgba { r, g, b, a }
+    }
+
+    /// Divides the color channels by the alpha channel.
+    ///
+    /// A fully transparent color has no color information, so it becomes a
+    /// transparent black.
+    pub fn unpremultiply(&self) -> Rgba<T>
+    where
+        T::Channel: Float,
+    {
+        let zero = T::Channel::from_f64(0.0);
+        if self.a <= zero {
+            return Rgba::new(zero, zero, zero, zero);
+        }
+        Rgba {
+            r: self.r / self.a,
+            g: self.g / self.a,
+            b: self.b / self.a,
+            a: self.a,
+        }
+    }
+
+    /// Blends this color over an opaque background with the "source over" operator.
+    ///
+    /// This gives the same result as [`Rgba::composite_over`] on the straight color.
+    pub fn composite_over(&self, background: &Rgb<T>) -> Rgb<T>
+    where
+        T::Channel: Float,
+    {
+        let coverage = T::Channel::from_f64(1.0) - self.a;
+        Rgb {
+            r: self.r + background.r * coverage,
+            g: self.g + background.g * coverage,
+            b: self.b + background.b * coverage,
+        }
+    }
+
+    /// Gets the relative luminance of this color blended over an opaque background.
+    ///
+    /// Like [`Luminance::relative_luminance`], this weighs the blended channels without
+    /// decoding them, so it isn't the luminance that the WCAG contrast checks use. Use
+    /// [`PremultipliedRgba::composited_wcag_relative_luminance`] for sRGB colors. See
+    /// [`PremultipliedRgba::composite_over`].
+    pub fn composited_luminance(&self, background: &Rgb<T>) -> T::Weighted
+    where
+        T::Channel: Float,
+    {
+        self.composite_over(background).relative_luminance()
+    }
+
+    /// Gets the [WCAG relative luminance](Luminance::wcag_relative_luminance) of this
+    /// color blended over an opaque background.
+    ///
+    /// The channels are blended as they are, like by
+    /// [`PremultipliedRgba::composite_over`], and then decoded with the sRGB transfer
+    /// function.
+    pub fn composited_wcag_relative_luminance(&self, background: &Rgb<T>) -> T::Weighted
+    where
+        T::Channel: Float,
+    {
+        self.composite_over(background).wcag_relative_luminance()
+    }
+}
+
+impl<T: LuminanceValue> Luminance<T> for PremultipliedRgba<T>
+where
+    T::Channel: Float,
+{
+    /// Gets the un-premultiplied color channels, ignoring the alpha channel.
+    fn luminance_rgb(&self) -> Rgb<T> {
+        self.unpremultiply().rgb()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_composite_extremes() {
+        let gray = Rgb::<f64>::new(0.5, 0.5, 0.5);
+        let opaque = Rgba::<f64>::new(1.0, 0.0, 0.0, 1.0);
+        let clear = Rgba::<f64>::new(1.0, 0.0, 0.0, 0.0);
+        assert_eq!(
+            opaque.composited_luminance(&gray),
+            opaque.relative_luminance()
+        );
+        assert_eq!(clear.composited_luminance(&gray), gray.relative_luminance());
+    }
+
+    #[test]
+    fn test_composited_wcag_relative_luminance_decodes() {
+        let white = Rgb::<f64>::new(1.0, 1.0, 1.0);
+        let overlay = Rgba::<f64>::new(0.0, 0.0, 0.0, 0.5);
+        let expected = Rgb::<f64>::new(0.5, 0.5, 0.5).wcag_relative_luminance();
+        assert!((overlay.composited_wcag_relative_luminance(&white) - 0.214).abs() < 0.001);
+        assert_eq!(
+            overlay
+                .premultiply()
+                .composited_wcag_relative_luminance(&white),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_premultiplied_matches_straight() {
+        let gray = Rgb::<f64>::new(0.5, 0.5, 0.5);
+        let straight = Rgba::<f64>::new(0.2, 0.4, 0.8, 0.25);
+        let premultiplied = straight.premultiply();
+        assert!(
+            (premultiplied.composited_luminance(&gray) - straight.composited_luminance(&gray))
+                .abs()
+                < 1e-12
+        );
+        assert!((premultiplied.relative_luminance() - straight.relative_luminance()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_unpremultiply_transparent() {
+        let clear = PremultipliedRgba::<f32>::new(0.0, 0.0, 0.0, 0.0);
+        assert_eq!(clear.relative_luminance(), 0.0);
+    }
+}
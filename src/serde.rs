@@ -0,0 +1,134 @@
+use crate::{LuminanceValue, Rgb, Rgba};
+use ::serde::de::value::MapAccessDeserializer;
+use ::serde::de::{self, MapAccess, Visitor};
+use ::serde::{Deserialize, Deserializer};
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+/// The fields of an [`Rgb`].
+#[derive(Deserialize)]
+#[serde(rename = "Rgb")]
+struct RgbFields<C> {
+    r: C,
+    g: C,
+    b: C,
+}
+
+/// The fields of an [`Rgba`].
+#[derive(Deserialize)]
+#[serde(rename = "Rgba")]
+struct RgbaFields<C> {
+    r: C,
+    g: C,
+    b: C,
+    a: C,
+}
+
+/// Visits either a color string or a map of fields.
+struct ColorVisitor<Color, Fields>(PhantomData<(Color, Fields)>);
+
+impl<'de, Color, Fields> Visitor<'de> for ColorVisitor<Color, Fields>
+where
+    Color: FromStr + From<Fields>,
+    Color::Err: fmt::Display,
+    Fields: Deserialize<'de>,
+{
+    type Value = Color;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a color string or a map of channels")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Color, E> {
+        s.parse().map_err(E::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Color, A::Error> {
+        Fields::deserialize(MapAccessDeserializer::new(map)).map(Color::from)
+    }
+}
+
+/// Deserializes a color string or a map of fields in human-readable formats, and only
+/// the fields in other formats.
+fn deserialize_color<'de, D, Color, Fields>(deserializer: D) -> Result<Color, D::Error>
+where
+    D: Deserializer<'de>,
+    Color: FromStr + From<Fields>,
+    Color::Err: fmt::Display,
+    Fields: Deserialize<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(ColorVisitor(PhantomData))
+    } else {
+        Fields::deserialize(deserializer).map(Color::from)
+    }
+}
+
+impl<T: LuminanceValue> From<RgbFields<T::Channel>> for Rgb<T> {
+    fn from(RgbFields { r, g, b }: RgbFields<T::Channel>) -> Self {
+        Rgb::new(r, g, b)
+    }
+}
+
+impl<T: LuminanceValue> From<RgbaFields<T::Channel>> for Rgba<T> {
+    fn from(RgbaFields { r, g, b, a }: RgbaFields<T::Channel>) -> Self {
+        Rgba::new(r, g, b, a)
+    }
+}
+
+/// Deserializes either the channels, like `{ "r": 1.0, "g": 0.0, "b": 0.0 }`, or a
+/// color string that can be parsed, like `"#ff0000"` or `"rgb(255 0 0)"`.
+///
+/// Formats that aren't human-readable only accept the channels.
+impl<'de, T> Deserialize<'de> for Rgb<T>
+where
+    T: LuminanceValue,
+    T::Channel: Deserialize<'de>,
+    Rgb<T>: FromStr,
+    <Rgb<T> as FromStr>::Err: fmt::Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_color::<D, Self, RgbFields<T::Channel>>(deserializer)
+    }
+}
+
+/// Deserializes either the channels, like `{ "r": 1.0, "g": 0.0, "b": 0.0, "a": 0.5 }`,
+/// or a color string that can be parsed, like `"#ff000080"`.
+///
+/// Formats that aren't human-readable only accept the channels.
+impl<'de, T> Deserialize<'de> for Rgba<T>
+where
+    T: LuminanceValue,
+    T::Channel: Deserialize<'de>,
+    Rgba<T>: FromStr,
+    <Rgba<T> as FromStr>::Err: fmt::Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_color::<D, Self, RgbaFields<T::Channel>>(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::serde::de::value::{Error, MapDeserializer, StrDeserializer};
+    use ::serde::de::IntoDeserializer;
+
+    #[test]
+    fn test_deserialize_string() {
+        let deserializer: StrDeserializer<'_, Error> = "#ff0000".into_deserializer();
+        let red = Rgb::<f32>::deserialize(deserializer).unwrap();
+        assert_eq!(red.relative_luminance(), 0.2126);
+        let deserializer: StrDeserializer<'_, Error> = "#nope".into_deserializer();
+        assert!(Rgba::<u8>::deserialize(deserializer).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_fields() {
+        let fields = [("r", 0u8), ("g", 0), ("b", 255), ("a", 128)];
+        let deserializer: MapDeserializer<'_, _, Error> = MapDeserializer::new(fields.into_iter());
+        let blue = Rgba::<u8>::deserialize(deserializer).unwrap();
+        assert_eq!((blue.b, blue.a), (255, 128));
+    }
+}
@@ -0,0 +1,146 @@
+//! Solving for the [`Oklch`] lightness that gives a color a target relative luminance.
+//!
+//! ```
+//! use relative_luminance::solve::Solver;
+//! use relative_luminance::{Luminance, Rgb};
+//!
+//! let teal: Rgb<f64> = Rgb::new(0.0, 0.5, 0.5);
+//! let solution = Solver::default().solve_color(&teal, 0.4);
+//!
+//! assert!(solution.converged);
+//! assert!((solution.color.wcag_relative_luminance() - 0.4).abs() < 1e-6);
+//! ```
+use crate::polar::gamut_map_oklch;
+use crate::transfer::Srgb;
+use crate::{Float, Luminance, LuminanceValue, Oklab, Oklch, Rgb};
+
+/// Binary-searches the Oklch lightness axis, keeping the hue and as much of the chroma
+/// as fits in the sRGB gamut.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Solver<F> {
+    /// The largest difference from the target luminance that counts as converged.
+    pub tolerance: F,
+    /// The most lightnesses to try before giving up.
+    pub max_iterations: usize,
+}
+
+impl<F: Float> Default for Solver<F> {
+    /// A tolerance of 1e-6, and at most 64 iterations.
+    fn default() -> Self {
+        Solver {
+            tolerance: F::from_f64(1e-6),
+            max_iterations: 64,
+        }
+    }
+}
+
+/// The result of a [`Solver`].
+#[derive(Clone, Copy, Debug)]
+pub struct Solution<F: LuminanceValue<Channel = F>> {
+    /// The color, encoded with [`Srgb`].
+    pub color: Rgb<F>,
+    /// The Oklch lightness of the color.
+    pub lightness: F,
+    /// The relative luminance of the color.
+    pub luminance: F,
+    /// How many lightnesses were tried.
+    pub iterations: usize,
+    /// If the luminance is within the tolerance of the target.
+    pub converged: bool,
+}
+
+impl<F> Solver<F>
+where
+    F: Float + LuminanceValue<Channel = F, Weighted = F>,
+{
+    /// Creates a new `Solver<F>`.
+    pub fn new(tolerance: F, max_iterations: usize) -> Self {
+        Solver {
+            tolerance,
+            max_iterations,
+        }
+    }
+
+    /// Finds the lightness of a chroma and hue that gives the target relative
+    /// luminance.
+    ///
+    /// If the solver doesn't converge, like when the iterations run out or the target
+    /// is outside of [0.0, 1.0], the last lightness that was tried is returned.
+    ///
+    /// ```
+    /// use relative_luminance::solve::Solver;
+    ///
+    /// let solver = Solver::new(1e-3f32, 64);
+    /// let solution = solver.solve(0.1, 30.0, 0.2);
+    ///
+    /// assert!(solution.converged);
+    /// assert!(solution.iterations < 64);
+    /// assert!(!solver.solve(0.1, 30.0, 1.5).converged);
+    /// ```
+    pub fn solve(&self, chroma: F, hue: F, target: F) -> Solution<F> {
+        let f = F::from_f64;
+        let (mut darkest, mut lightest) = (f(0.0), f(1.0));
+        let mut solution = Solution {
+            color: Rgb::new(f(0.0), f(0.0), f(0.0)),
+            lightness: f(0.0),
+            luminance: f(0.0),
+            iterations: 0,
+            converged: false,
+        };
+        while solution.iterations < self.max_iterations {
+            let lightness = (darkest + lightest) / f(2.0);
+            let linear = gamut_map_oklch(Oklch::new(lightness, chroma, hue)).0;
+            let luminance = linear.relative_luminance();
+            solution = Solution {
+                color: linear.encode(&Srgb),
+                lightness,
+                luminance,
+                iterations: solution.iterations + 1,
+                converged: (luminance - target).abs() <= self.tolerance,
+            };
+            if solution.converged {
+                break;
+            }
+            // Luminance rises with lightness.
+            if luminance < target {
+                darkest = lightness;
+            } else {
+                lightest = lightness;
+            }
+        }
+        solution
+    }
+
+    /// Finds the lightness of a color's Oklch chroma and hue that gives the target
+    /// relative luminance.
+    ///
+    /// See [`Solver::solve`].
+    pub fn solve_color<C: Luminance<F> + ?Sized>(&self, base: &C, target: F) -> Solution<F> {
+        let base = Oklch::from_oklab(&Oklab::from_linear_rgb(&base.linear_rgb()));
+        self.solve(base.c, base.h, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iteration_limit() {
+        let solution = Solver::new(1e-12f64, 3).solve(0.1, 200.0, 0.3);
+        assert_eq!(solution.iterations, 3);
+        assert!(!solution.converged);
+        assert_eq!(Solver::new(0.5f64, 0).solve(0.1, 200.0, 0.3).iterations, 0);
+    }
+
+    #[test]
+    fn test_keeps_hue_of_base() {
+        let red = Rgb::<f64>::new(1.0, 0.0, 0.0);
+        let solution = Solver::default().solve_color(&red, 0.05);
+        assert!(solution.converged);
+        assert!((solution.luminance - 0.05).abs() < 1e-6);
+        let Rgb { r, g, b } = solution.color;
+        assert!(r > g && r > b);
+    }
+}
@@ -0,0 +1,165 @@
+//! Luminance from a spectral power distribution, using the CIE 1924 photopic
+//! luminosity function V(λ).
+//!
+//! Spectral power distributions are given as `(wavelength, value)` pairs, with the
+//! wavelength in nanometers, sorted by wavelength. Values between samples are linearly
+//! interpolated, and two samples with the same wavelength make a step.
+//!
+//! ```
+//! use relative_luminance::spectral;
+//!
+//! // A narrow-band green LED
+//! let green = [(550.0, 0.0), (555.0, 1.0), (560.0, 0.0)];
+//! // A narrow-band red LED with the same radiance
+//! let red = [(645.0, 0.0), (650.0, 1.0), (655.0, 0.0)];
+//!
+//! assert!(spectral::luminance(&green) > spectral::luminance(&red) * 5.0);
+//! ```
+/// The maximum luminous efficacy of photopic vision, in lumens per watt.
+pub const MAX_LUMINOUS_EFFICACY: f64 = 683.0;
+
+/// The first wavelength of [`PHOTOPIC`], in nanometers.
+const FIRST_WAVELENGTH: f64 = 380.0;
+/// The distance between the wavelengths of [`PHOTOPIC`], in nanometers.
+const STEP: f64 = 5.0;
+
+/// The CIE 1924 photopic luminosity function from 380 nm to 780 nm, in 5 nm steps.
+const PHOTOPIC: [f64; 81] = [
+    0.000039, 0.000064, 0.00012, 0.000217, 0.000396, 0.00064, 0.00121, 0.00218, 0.004, 0.0073,
+    0.0116, 0.01684, 0.023, 0.0298, 0.038, 0.048, 0.06, 0.0739, 0.09098, 0.1126, 0.13902, 0.1693,
+    0.20802, 0.2586, 0.323, 0.4073, 0.503, 0.6082, 0.71, 0.7932, 0.862, 0.91485, 0.954, 0.9803,
+    0.99495, 1.0, 0.995, 0.9786, 0.952, 0.9154, 0.87, 0.8163, 0.757, 0.6949, 0.631, 0.5668, 0.503,
+    0.4412, 0.381, 0.321, 0.265, 0.217, 0.175, 0.1382, 0.107, 0.0816, 0.061, 0.04458, 0.032,
+    0.0232, 0.017, 0.01192, 0.00821, 0.005723, 0.004102, 0.002929, 0.002091, 0.001484, 0.001047,
+    0.00074, 0.00052, 0.000361, 0.000249, 0.000172, 0.00012, 0.0000848, 0.00006, 0.0000424,
+    0.00003, 0.0000212, 0.0000149,
+];
+
+/// Gets the photopic luminous efficiency V(λ) of a wavelength in nanometers.
+///
+/// This is 1.0 at 555 nm, and 0.0 outside of the visible range.
+///
+/// ```
+/// # use relative_luminance::spectral::photopic_efficiency;
+/// assert_eq!(photopic_efficiency(555.0), 1.0);
+/// assert_eq!(photopic_efficiency(300.0), 0.0);
+/// ```
+pub fn photopic_efficiency(wavelength: f64) -> f64 {
+    let position = (wavelength - FIRST_WAVELENGTH) / STEP;
+    if !(0.0..=(PHOTOPIC.len() - 1) as f64).contains(&position) {
+        return 0.0;
+    }
+    let index = position.floor() as usize;
+    let Some(&next) = PHOTOPIC.get(index + 1) else {
+        return PHOTOPIC[index];
+    };
+    let t = position - index as f64;
+    PHOTOPIC[index] + (next - PHOTOPIC[index]) * t
+}
+
+/// Gets the photopic integral ∫ S(λ) V(λ) dλ of a spectral power distribution.
+///
+/// This is unscaled, so it can be compared between spectra, or used as a relative
+/// luminance with [`relative_luminance`].
+pub fn luminance(spectrum: &[(f64, f64)]) -> f64 {
+    spectrum
+        .windows(2)
+        // Samples with the same wavelength make a step with no width, which adds nothing.
+        .filter(|window| window[1].0 > window[0].0)
+        .map(|window| {
+            let (start, start_power) = window[0];
+            let (end, end_power) = window[1];
+            let power = |wavelength: f64| {
+                let t = (wavelength - start) / (end - start);
+                start_power + (end_power - start_power) * t
+            };
+            // Split the segment at every wavelength of the table, so that the
+            // trapezoidal rule follows the shape of V(λ).
+            let first_step = ((start - FIRST_WAVELENGTH) / STEP).floor() + 1.0;
+            let steps = (first_step as i64..)
+                .map(|step| FIRST_WAVELENGTH + step as f64 * STEP)
+                .take_while(|&wavelength| wavelength < end);
+            let mut previous = (start, start_power * photopic_efficiency(start));
+            let mut integral = 0.0;
+            for wavelength in steps.chain(core::iter::once(end)) {
+                let value = power(wavelength) * photopic_efficiency(wavelength);
+                integral += (previous.1 + value) / 2.0 * (wavelength - previous.0);
+                previous = (wavelength, value);
+            }
+            integral
+        })
+        .sum()
+}
+
+/// Gets the absolute luminance, in cd/m², of a spectral radiance distribution in
+/// W·sr⁻¹·m⁻²·nm⁻¹.
+///
+/// ```
+/// use relative_luminance::spectral;
+///
+/// // 1 W·sr⁻¹·m⁻² spread evenly over 550-560 nm
+/// let spectrum = [(550.0, 0.1), (560.0, 0.1)];
+///
+/// assert!((spectral::absolute_luminance(&spectrum) - 681.28).abs() < 0.01);
+/// ```
+pub fn absolute_luminance(spectrum: &[(f64, f64)]) -> f64 {
+    MAX_LUMINOUS_EFFICACY * luminance(spectrum)
+}
+
+/// Gets the relative luminance (Y) of a spectral power distribution, relative to the
+/// spectral power distribution of a reference white, or [`None`] if the white has no
+/// luminance.
+///
+/// ```
+/// use relative_luminance::spectral;
+///
+/// let white = [(380.0, 1.0), (780.0, 1.0)];
+/// let half = [(380.0, 0.5), (780.0, 0.5)];
+///
+/// assert_eq!(spectral::relative_luminance(&white, &white), Some(1.0));
+/// assert_eq!(spectral::relative_luminance(&half, &white), Some(0.5));
+/// assert_eq!(spectral::relative_luminance(&half, &[]), None);
+/// ```
+pub fn relative_luminance(spectrum: &[(f64, f64)], white: &[(f64, f64)]) -> Option<f64> {
+    let white = luminance(white);
+    (white != 0.0).then(|| luminance(spectrum) / white)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interpolates_between_samples() {
+        let expected = (PHOTOPIC[34] + PHOTOPIC[35]) / 2.0;
+        assert!((photopic_efficiency(552.5) - expected).abs() < 1e-12);
+        assert_eq!(photopic_efficiency(780.0), PHOTOPIC[80]);
+    }
+
+    #[test]
+    fn test_equal_energy_integral() {
+        // The integral of V(λ) over the visible range is about 106.86 nm.
+        let sparse = [(380.0, 1.0), (780.0, 1.0)];
+        let dense: Vec<_> = (0..PHOTOPIC.len())
+            .map(|i| (FIRST_WAVELENGTH + i as f64 * STEP, 1.0))
+            .collect();
+        assert!((luminance(&sparse) - 106.86).abs() < 0.01);
+        assert!((luminance(&dense) - luminance(&sparse)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_samples_between_table_wavelengths() {
+        let spectrum = [(551.0, 1.0), (553.0, 1.0)];
+        let expected = (photopic_efficiency(551.0) + photopic_efficiency(553.0)) / 2.0 * 2.0;
+        assert!((luminance(&spectrum) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_repeated_wavelengths_make_a_step() {
+        let step = [(540.0, 0.0), (550.0, 0.0), (550.0, 1.0), (560.0, 1.0)];
+        let expected = luminance(&[(550.0, 1.0), (560.0, 1.0)]);
+        assert_eq!(luminance(&step), expected);
+        let black = [(380.0, 0.0), (780.0, 0.0)];
+        assert_eq!(relative_luminance(&step, &black), None);
+    }
+}
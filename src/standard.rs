@@ -0,0 +1,271 @@
+//! Luminance coefficients of standard RGB color spaces.
+//!
+//! The weights of a [`LuminanceValue`] are the defaults used by
+//! [`Luminance::relative_luminance`]. A [`Standard`] lets you select a different set of
+//! weights for the same numerical type with [`Luminance::relative_luminance_as`].
+//!
+//! ```
+//! use relative_luminance::standard::{Bt2020, Bt601, Bt709};
+//! use relative_luminance::{Luminance, Rgb};
+//!
+//! let green: Rgb<f32> = Rgb::new(0.0, 1.0, 0.0);
+//!
+//! assert_eq!(green.relative_luminance_as::<Bt709>(), 0.7152);
+//! assert_eq!(green.relative_luminance_as::<Bt601>(), 0.587);
+//! assert_eq!(green.relative_luminance_as::<Bt2020>(), 0.678);
+//! ```
+//!
+//! [`Luminance::relative_luminance`]: crate::Luminance::relative_luminance
+//! [`Luminance::relative_luminance_as`]: crate::Luminance::relative_luminance_as
+use crate::colorimetry::{Chromaticity, Primaries};
+use crate::{Float, IntegerWeight, LuminanceValue};
+
+/// A set of luminance weights for a numerical type.
+///
+/// ```
+/// use relative_luminance::{Luminance, Rgb, Standard};
+///
+/// /// Treats every channel equally.
+/// struct Average;
+///
+/// impl Standard<f32> for Average {
+///     const RED_WEIGHT: f32 = 1.0 / 3.0;
+///     const GREEN_WEIGHT: f32 = 1.0 / 3.0;
+///     const BLUE_WEIGHT: f32 = 1.0 / 3.0;
+/// }
+///
+/// let red: Rgb<f32> = Rgb::new(1.0, 0.0, 0.0);
+///
+/// assert_eq!(red.relative_luminance_as::<Average>(), 1.0 / 3.0);
+/// ```
+pub trait Standard<T: LuminanceValue> {
+    /// The modifier for the red channel.
+    const RED_WEIGHT: T::Weight;
+    /// The modifier for the green channel.
+    const GREEN_WEIGHT: T::Weight;
+    /// The modifier for the blue channel.
+    const BLUE_WEIGHT: T::Weight;
+}
+
+/// The [ITU-R BT.709][bt709] coefficients (0.2126, 0.7152, 0.0722), shared by sRGB.
+///
+/// These are the weights of the [`LuminanceValue`] itself, so this is the same as
+/// [`Luminance::relative_luminance`](crate::Luminance::relative_luminance).
+///
+/// [bt709]: https://www.itu.int/rec/R-REC-BT.709
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bt709;
+
+impl<T: LuminanceValue> Standard<T> for Bt709 {
+    const RED_WEIGHT: T::Weight = T::RED_WEIGHT;
+    const GREEN_WEIGHT: T::Weight = T::GREEN_WEIGHT;
+    const BLUE_WEIGHT: T::Weight = T::BLUE_WEIGHT;
+}
+
+/// The [ITU-R BT.601][bt601] coefficients (0.299, 0.587, 0.114) of standard-definition
+/// video.
+///
+/// [bt601]: https://www.itu.int/rec/R-REC-BT.601
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bt601;
+
+/// The [ITU-R BT.2020][bt2020] coefficients (0.2627, 0.6780, 0.0593) of wide-gamut UHD
+/// video.
+///
+/// [bt2020]: https://www.itu.int/rec/R-REC-BT.2020
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bt2020;
+
+/// The [Display P3][display-p3] coefficients (0.2289746, 0.6917385, 0.0792869) of
+/// wide-gamut Apple displays and the CSS `display-p3` color space.
+///
+/// These are derived from [`DisplayP3::PRIMARIES`].
+///
+/// [display-p3]: https://www.w3.org/TR/css-color-4/#predefined-display-p3
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DisplayP3;
+
+impl DisplayP3 {
+    /// The DCI-P3 primaries with a D65 white point.
+    pub const PRIMARIES: Primaries<f64> = Primaries {
+        red: Chromaticity::new(0.680, 0.320),
+        green: Chromaticity::new(0.265, 0.690),
+        blue: Chromaticity::new(0.150, 0.060),
+        white: Chromaticity::<f64>::D65,
+    };
+}
+
+/// The [Adobe RGB (1998)][adobe-rgb] coefficients (0.297345, 0.6273636, 0.0752915)
+/// commonly used in photography and print.
+///
+/// These are derived from [`AdobeRgb::PRIMARIES`].
+///
+/// [adobe-rgb]: https://www.adobe.com/digitalimag/adobergb.html
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AdobeRgb;
+
+impl AdobeRgb {
+    /// The Adobe RGB (1998) primaries with a D65 white point.
+    pub const PRIMARIES: Primaries<f64> = Primaries {
+        red: Chromaticity::new(0.64, 0.33),
+        green: Chromaticity::new(0.21, 0.71),
+        blue: Chromaticity::new(0.15, 0.06),
+        white: Chromaticity::<f64>::D65,
+    };
+}
+
+/// The [ProPhoto RGB][prophoto-rgb] (ROMM RGB) coefficients (0.2880711, 0.7118432,
+/// 0.0000857) used by raw photo editors.
+///
+/// These are derived from [`ProPhotoRgb::PRIMARIES`]. Note that unlike the other
+/// standards, ProPhoto RGB uses a D50 white point.
+///
+/// [prophoto-rgb]: https://en.wikipedia.org/wiki/ProPhoto_RGB_color_space
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ProPhotoRgb;
+
+impl ProPhotoRgb {
+    /// The ROMM RGB primaries with a D50 white point.
+    pub const PRIMARIES: Primaries<f64> = Primaries {
+        red: Chromaticity::new(0.7347, 0.2653),
+        green: Chromaticity::new(0.1596, 0.8404),
+        blue: Chromaticity::new(0.0366, 0.0001),
+        white: Chromaticity::<f64>::D50,
+    };
+
+    /// Decodes an encoded ProPhoto RGB channel in [0.0, 1.0] to linear light.
+    ///
+    /// ProPhoto RGB uses a 1.8 gamma with a short linear segment near black, so its
+    /// channels should be decoded with this before calculating luminance. This is also
+    /// available as a [`TransferFunction`](crate::transfer::TransferFunction).
+    ///
+    /// ```
+    /// use relative_luminance::standard::ProPhotoRgb;
+    ///
+    /// assert_eq!(ProPhotoRgb::to_linear(0.0f32), 0.0);
+    /// assert_eq!(ProPhotoRgb::to_linear(1.0f32), 1.0);
+    /// ```
+    pub fn to_linear<F: Float>(encoded: F) -> F {
+        // 16 times the 1/512 linear threshold of the encoding function
+        if encoded < F::from_f64(16.0 / 512.0) {
+            encoded / F::from_f64(16.0)
+        } else {
+            encoded.powf(F::from_f64(1.8))
+        }
+    }
+}
+
+/// The [ACEScg][acescg] coefficients (0.2722287, 0.6740818, 0.0536895) of scene-linear
+/// AP1 primaries, as used in VFX and film pipelines.
+///
+/// These are derived from [`AcesCg::PRIMARIES`]. ACEScg is already linear, so its
+/// channels can be weighted directly.
+///
+/// [acescg]: https://docs.acescentral.com/specifications/acescg/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AcesCg;
+
+impl AcesCg {
+    /// The AP1 primaries with the ACES white point (approximately D60).
+    pub const PRIMARIES: Primaries<f64> = Primaries {
+        red: Chromaticity::new(0.713, 0.293),
+        green: Chromaticity::new(0.165, 0.830),
+        blue: Chromaticity::new(0.128, 0.044),
+        white: Chromaticity::new(0.32168, 0.33767),
+    };
+}
+
+macro_rules! impl_standard {
+    ($standard:ty, $red:literal, $green:literal, $blue:literal, $($t:ty),*) => {
+        $(
+            impl Standard<$t> for $standard {
+                const RED_WEIGHT: $t = $red;
+                const GREEN_WEIGHT: $t = $green;
+                const BLUE_WEIGHT: $t = $blue;
+            }
+        )*
+    };
+}
+
+impl_standard!(Bt601, 0.299, 0.587, 0.114, f32, f64);
+impl_standard!(Bt2020, 0.2627, 0.6780, 0.0593, f32, f64);
+impl_standard!(DisplayP3, 0.2289746, 0.6917385, 0.0792869, f32, f64);
+impl_standard!(AdobeRgb, 0.297345, 0.6273636, 0.0752915, f32, f64);
+impl_standard!(ProPhotoRgb, 0.2880711, 0.7118432, 0.0000857, f32, f64);
+impl_standard!(AcesCg, 0.2722287, 0.6740818, 0.0536895, f32, f64);
+
+macro_rules! impl_integer_standard {
+    ($standard:ty, $($t:ty),*) => {
+        $(
+            impl Standard<$t> for $standard {
+                const RED_WEIGHT: IntegerWeight =
+                    IntegerWeight(<$standard as Standard<f32>>::RED_WEIGHT);
+                const GREEN_WEIGHT: IntegerWeight =
+                    IntegerWeight(<$standard as Standard<f32>>::GREEN_WEIGHT);
+                const BLUE_WEIGHT: IntegerWeight =
+                    IntegerWeight(<$standard as Standard<f32>>::BLUE_WEIGHT);
+            }
+        )*
+    };
+}
+
+impl_integer_standard!(Bt601, u8, u16);
+impl_integer_standard!(Bt2020, u8, u16);
+impl_integer_standard!(DisplayP3, u8, u16);
+impl_integer_standard!(AdobeRgb, u8, u16);
+impl_integer_standard!(ProPhotoRgb, u8, u16);
+impl_integer_standard!(AcesCg, u8, u16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Luminance, Rgb, Weights};
+
+    fn assert_weights_match<S: Standard<f64>>(primaries: &Primaries<f64>) {
+        let derived = primaries.weights();
+        let weights = Weights::<f64>::from_standard::<S>();
+        assert!((derived.r - weights.r).abs() < 1e-7);
+        assert!((derived.g - weights.g).abs() < 1e-7);
+        assert!((derived.b - weights.b).abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_white_is_one() {
+        let white = Rgb::<f64>::new(1.0, 1.0, 1.0);
+        assert!((white.relative_luminance_as::<Bt709>() - 1.0).abs() < 1e-6);
+        assert!((white.relative_luminance_as::<Bt601>() - 1.0).abs() < 1e-6);
+        assert!((white.relative_luminance_as::<Bt2020>() - 1.0).abs() < 1e-6);
+        assert!((white.relative_luminance_as::<DisplayP3>() - 1.0).abs() < 1e-6);
+        assert!((white.relative_luminance_as::<AdobeRgb>() - 1.0).abs() < 1e-6);
+        assert!((white.relative_luminance_as::<ProPhotoRgb>() - 1.0).abs() < 1e-6);
+        assert!((white.relative_luminance_as::<AcesCg>() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_display_p3_matches_primaries() {
+        assert_weights_match::<DisplayP3>(&DisplayP3::PRIMARIES);
+    }
+
+    #[test]
+    fn test_adobe_rgb_matches_primaries() {
+        assert_weights_match::<AdobeRgb>(&AdobeRgb::PRIMARIES);
+    }
+
+    #[test]
+    fn test_prophoto_rgb_matches_primaries() {
+        assert_weights_match::<ProPhotoRgb>(&ProPhotoRgb::PRIMARIES);
+    }
+
+    #[test]
+    fn test_acescg_matches_primaries() {
+        assert_weights_match::<AcesCg>(&AcesCg::PRIMARIES);
+    }
+
+    #[test]
+    fn test_prophoto_rgb_to_linear_is_continuous() {
+        let threshold = 16.0 / 512.0;
+        let below = ProPhotoRgb::to_linear(threshold - 1e-9);
+        let above = ProPhotoRgb::to_linear(threshold);
+        assert!((above - below).abs() < 1e-6);
+    }
+}
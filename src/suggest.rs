@@ -0,0 +1,134 @@
+//! Suggestions of colors that have enough contrast with a given color, for building
+//! accessible palettes.
+//!
+//! ```
+//! use relative_luminance::contrast::Requirement;
+//! use relative_luminance::{suggest, Rgb};
+//!
+//! let background: Rgb<f64> = Rgb::new(0.1, 0.1, 0.3);
+//! let suggestions = suggest::complements(&background, Requirement::Wcag(4.5), 4, 0.8);
+//!
+//! assert_eq!(suggestions.len(), 4);
+//! assert!(suggestions
+//!     .iter()
+//!     .all(|color| Requirement::Wcag(4.5).is_met(color, &background)));
+//! ```
+use crate::contrast::Requirement;
+use crate::polar::{gamut_map_oklch, search};
+use crate::transfer::Srgb;
+use crate::{Float, Hsl, Luminance, LuminanceValue, Oklch, Rgb};
+
+/// Suggests up to `count` colors with evenly spaced hues, starting with the complement
+/// of the fixed color's hue, which all meet a contrast requirement as text on the
+/// fixed color.
+///
+/// Each suggestion has the given HSL saturation, and the HSL lightness closest to 0.5
+/// that meets the requirement, either darker or lighter than the fixed color. Hues
+/// where no lightness meets the requirement are skipped, so fewer than `count` colors
+/// may be returned. The suggestions are encoded with [`Srgb`].
+pub fn complements<F, C>(
+    fixed: &C,
+    requirement: Requirement<F>,
+    count: usize,
+    saturation: F,
+) -> Vec<Rgb<F>>
+where
+    F: Float + LuminanceValue<Channel = F, Weighted = F>,
+    C: Luminance<F> + ?Sized,
+{
+    let f = F::from_f64;
+    let fixed_luminance = fixed.wcag_relative_luminance();
+    let fixed_hue = Hsl::from_rgb(&fixed.linear_rgb().encode(&Srgb)).h;
+    (0..count)
+        .filter_map(|i| {
+            let hue = fixed_hue + f(180.0) + f(360.0) * f(i as f64) / f(count as f64);
+            let color = |lightness| Hsl::new(hue, saturation, lightness).to_rgb();
+            let meets = |lightness| requirement.is_met(&color(lightness), fixed);
+            let luminance = |lightness| color(lightness).wcag_relative_luminance();
+
+            // The lightest darker color and the darkest lighter color that meet the
+            // requirement, since contrast falls toward the fixed color's luminance.
+            let darker = search(f(0.0), f(1.0), |l| {
+                luminance(l) < fixed_luminance && meets(l)
+            });
+            let lighter = search(f(1.0), f(0.0), |l| {
+                luminance(l) > fixed_luminance && meets(l)
+            });
+            let distance = |l: F| (l - f(0.5)).abs();
+            let lightness = match (darker, lighter) {
+                (Some(d), Some(l)) => Some(if distance(d) <= distance(l) { d } else { l }),
+                (d, l) => d.or(l),
+            };
+            lightness.map(color)
+        })
+        .collect()
+}
+
+/// Generates `count` colors with evenly spaced [`Oklch`] hues, starting at 0°, which
+/// all have about the same relative luminance, like the series of a chart.
+///
+/// Each color has the given Oklch chroma, or the most chroma that fits in the sRGB
+/// gamut with the luminance. Luminances outside of [0.0, 1.0] are clamped. The colors
+/// are encoded with [`Srgb`].
+///
+/// ```
+/// use relative_luminance::{suggest, Luminance};
+///
+/// let series = suggest::equal_luminance(5, 0.3f64, 0.15);
+///
+/// assert_eq!(series.len(), 5);
+/// assert!(series
+///     .iter()
+///     .all(|color| (color.wcag_relative_luminance() - 0.3).abs() < 1e-6));
+/// ```
+pub fn equal_luminance<F>(count: usize, luminance: F, chroma: F) -> Vec<Rgb<F>>
+where
+    F: Float + LuminanceValue<Channel = F, Weighted = F>,
+{
+    let f = F::from_f64;
+    (0..count)
+        .map(|i| {
+            let hue = f(360.0 * i as f64 / count as f64);
+            let color = |lightness| gamut_map_oklch(Oklch::new(lightness, chroma, hue)).0;
+            // Luminance rises with lightness, and black always passes.
+            let lightness = search(f(0.0), f(1.0), |l| {
+                color(l).relative_luminance() <= luminance
+            });
+            color(lightness.unwrap_or(f(0.0))).encode(&Srgb)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apca_suggestions_meet_requirement() {
+        let background = Rgb::<f64>::new(0.95, 0.9, 0.8);
+        let requirement = Requirement::Apca(75.0);
+        let suggestions = complements(&background, requirement, 6, 1.0);
+        assert_eq!(suggestions.len(), 6);
+        for color in &suggestions {
+            assert!(requirement.is_met(color, &background));
+        }
+    }
+
+    #[test]
+    fn test_equal_luminance_hues_differ() {
+        let series = equal_luminance(3, 0.5f64, 0.2);
+        let hues: Vec<_> = series.iter().map(|color| Hsl::from_rgb(color).h).collect();
+        assert!((hues[0] - hues[1]).abs() > 30.0 && (hues[1] - hues[2]).abs() > 30.0);
+        assert!(equal_luminance(4, 1.5f64, 0.2).iter().all(|color| (color
+            .wcag_relative_luminance()
+            - 1.0)
+            .abs()
+            < 1e-6));
+    }
+
+    #[test]
+    fn test_impossible_requirement_is_skipped() {
+        let gray = Rgb::<f64>::new(0.5, 0.5, 0.5);
+        assert!(complements(&gray, Requirement::Wcag(10.0), 3, 1.0).is_empty());
+    }
+}
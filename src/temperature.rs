@@ -0,0 +1,105 @@
+//! Colors of blackbody radiators by their correlated color temperature.
+//!
+//! This is useful for night-light and screen-dimming features, which shift the color
+//! of the screen towards lower temperatures.
+//!
+//! ```
+//! use relative_luminance::temperature;
+//!
+//! let candle = temperature::relative_luminance::<f64>(1900.0);
+//! let daylight = temperature::relative_luminance::<f64>(6500.0);
+//!
+//! assert!(candle < daylight);
+//! ```
+use crate::colorimetry::{self, Chromaticity};
+use crate::transfer::{Srgb, TransferFunction};
+use crate::{Float, Luminance, LuminanceValue, Rgb};
+
+/// The lowest temperature, in Kelvin, supported by the approximation.
+pub const MIN_KELVIN: f64 = 1667.0;
+/// The highest temperature, in Kelvin, supported by the approximation.
+pub const MAX_KELVIN: f64 = 25000.0;
+
+/// Gets the chromaticity of a point on the Planckian locus.
+///
+/// This uses the cubic spline approximation by [Kim et al.][kim], and clamps the
+/// temperature to [`MIN_KELVIN`] and [`MAX_KELVIN`].
+///
+/// ```
+/// # use relative_luminance::temperature::chromaticity;
+/// let d50ish = chromaticity(5000.0);
+///
+/// assert!((d50ish.x - 0.3451).abs() < 0.001);
+/// assert!((d50ish.y - 0.3516).abs() < 0.001);
+/// ```
+///
+/// [kim]: https://patents.google.com/patent/US7024034B2
+pub fn chromaticity(kelvin: f64) -> Chromaticity<f64> {
+    let t = kelvin.clamp(MIN_KELVIN, MAX_KELVIN);
+    let (t2, t3) = (t * t, t * t * t);
+    let x = if t <= 4000.0 {
+        -0.2661239e9 / t3 - 0.2343589e6 / t2 + 0.8776956e3 / t + 0.179910
+    } else {
+        -3.0258469e9 / t3 + 2.1070379e6 / t2 + 0.2226347e3 / t + 0.240390
+    };
+    let (x2, x3) = (x * x, x * x * x);
+    let y = if t <= 2222.0 {
+        -1.1063814 * x3 - 1.34811020 * x2 + 2.18555832 * x - 0.20219683
+    } else if t <= 4000.0 {
+        -0.9549476 * x3 - 1.37418593 * x2 + 2.09137015 * x - 0.16748867
+    } else {
+        3.0817580 * x3 - 5.87338670 * x2 + 3.75112997 * x - 0.37001483
+    };
+    Chromaticity::new(x, y)
+}
+
+/// Gets the gamma-encoded sRGB color of a blackbody radiator.
+///
+/// The color is scaled so that its brightest channel is 1.0, and channels outside of
+/// the sRGB gamut are clamped.
+///
+/// ```
+/// use relative_luminance::temperature;
+///
+/// let incandescent = temperature::to_rgb::<f32>(2700.0);
+///
+/// assert_eq!(incandescent.r, 1.0);
+/// assert!(incandescent.b < incandescent.g);
+/// ```
+pub fn to_rgb<F>(kelvin: f64) -> Rgb<F>
+where
+    F: Float + LuminanceValue<Channel = F>,
+{
+    let [r, g, b] = colorimetry::xyz_to_srgb(chromaticity(kelvin).to_xyz());
+    let max = [r, g, b].into_iter().fold(0.0, f64::max);
+    let channel = |linear: f64| F::from_f64(Srgb.encode(linear.max(0.0) / max));
+    Rgb::new(channel(r), channel(g), channel(b))
+}
+
+/// Gets the WCAG relative luminance of the color from [`to_rgb`].
+pub fn relative_luminance<F>(kelvin: f64) -> F
+where
+    F: Float + LuminanceValue<Channel = F, Weighted = F>,
+{
+    to_rgb::<F>(kelvin).wcag_relative_luminance()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_6500k_is_nearly_white() {
+        let rgb = to_rgb::<f64>(6500.0);
+        assert!(rgb.r > 0.95 && rgb.g > 0.95 && rgb.b > 0.95);
+    }
+
+    #[test]
+    fn test_luminance_increases_with_temperature_below_white() {
+        let luminances: Vec<f64> = [1700.0, 2700.0, 4000.0, 5500.0]
+            .into_iter()
+            .map(relative_luminance)
+            .collect();
+        assert!(luminances.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
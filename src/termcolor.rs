@@ -0,0 +1,51 @@
+use crate::{ansi, Luminance, Rgb};
+use ::termcolor::Color;
+
+/// Colors from the [`termcolor`](::termcolor) crate.
+///
+/// Named and ANSI colors are resolved with the default [`ansi`] palette. Named colors
+/// are the normal (not intense) colors, so [`Color::White`] is a light gray. Colors
+/// that may be added to `termcolor` in the future are treated as black.
+///
+/// ```
+/// use relative_luminance::Luminance;
+/// use termcolor::Color;
+///
+/// assert_eq!(Color::Rgb(0, 255, 0).relative_luminance(), 0.7152);
+/// assert_eq!(Color::Ansi256(15).relative_luminance(), 1.0);
+/// assert!(Color::White.relative_luminance() < 1.0);
+/// ```
+impl Luminance<f32> for Color {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        let rgb = match *self {
+            Color::Rgb(r, g, b) => Rgb::new(r, g, b),
+            Color::Ansi256(index) => ansi::indexed(index),
+            Color::Black => ansi::indexed(0),
+            Color::Red => ansi::indexed(1),
+            Color::Green => ansi::indexed(2),
+            Color::Yellow => ansi::indexed(3),
+            Color::Blue => ansi::indexed(4),
+            Color::Magenta => ansi::indexed(5),
+            Color::Cyan => ansi::indexed(6),
+            Color::White => ansi::indexed(7),
+            _ => ansi::indexed(0),
+        };
+        rgb.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_match_indexed() {
+        let named = [Color::Black, Color::Blue, Color::White];
+        for (color, index) in named.into_iter().zip([0, 4, 7]) {
+            assert_eq!(
+                color.relative_luminance(),
+                Color::Ansi256(index).relative_luminance()
+            );
+        }
+    }
+}
@@ -0,0 +1,317 @@
+//! Detecting if a UI theme is light or dark.
+//!
+//! ```
+//! use relative_luminance::theme::{self, Theme};
+//! use relative_luminance::Rgb;
+//!
+//! let background: Rgb<f32> = Rgb::new(0.12, 0.12, 0.14);
+//! let surfaces: [Rgb<f32>; 2] = [Rgb::new(0.2, 0.2, 0.22), Rgb::new(0.3, 0.3, 0.35)];
+//!
+//! assert_eq!(theme::detect_theme(&background, &surfaces), Theme::Dark);
+//! ```
+use crate::{Float, Luminance, LuminanceValue, Rgb};
+use core::fmt;
+
+/// The relative luminance where black and white text have the same
+/// [contrast ratio](Luminance::contrast_ratio), `sqrt(1.05 × 0.05) − 0.05`.
+pub const MIDDLE_LUMINANCE: f64 = 0.179_128_784_747_792;
+
+/// If a theme has light or dark backgrounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum Theme {
+    /// Light backgrounds with dark text.
+    Light,
+    /// Dark backgrounds with light text.
+    Dark,
+}
+
+impl Theme {
+    /// Classifies a background by its
+    /// [WCAG relative luminance](Luminance::wcag_relative_luminance).
+    ///
+    /// Backgrounds above [`MIDDLE_LUMINANCE`] are light, because black text has more
+    /// contrast on them than white text.
+    ///
+    /// ```
+    /// use relative_luminance::theme::Theme;
+    ///
+    /// assert_eq!(Theme::from_luminance(0.5f32), Theme::Light);
+    /// assert_eq!(Theme::from_luminance(0.1f32), Theme::Dark);
+    /// ```
+    pub fn from_luminance<F: Float>(luminance: F) -> Self {
+        if luminance > F::from_f64(MIDDLE_LUMINANCE) {
+            Theme::Light
+        } else {
+            Theme::Dark
+        }
+    }
+}
+
+/// Classifies a theme from its main background color and its surface colors, like
+/// cards and panels.
+///
+/// The background is weighted the same as all of the surfaces together, since it
+/// usually covers the most area, and the weighted relative luminance is classified
+/// with [`Theme::from_luminance`].
+pub fn detect_theme<T, B, C>(background: &B, surfaces: &[C]) -> Theme
+where
+    T: LuminanceValue,
+    T::Channel: Float,
+    T::Weighted: Float,
+    B: Luminance<T> + ?Sized,
+    C: Luminance<T>,
+{
+    let f = <T::Weighted as Float>::from_f64;
+    let background = background.wcag_relative_luminance();
+    let luminance = if surfaces.is_empty() {
+        background
+    } else {
+        let sum = surfaces.iter().fold(f(0.0), |sum, surface| {
+            sum + surface.wcag_relative_luminance()
+        });
+        let mean = sum / f(surfaces.len() as f64);
+        (background + mean) / f(2.0)
+    };
+    Theme::from_luminance(luminance)
+}
+
+/// A [`Theme`] that only changes when a luminance crosses an upper or lower bound,
+/// so it doesn't flicker when colors change continuously, like album art or
+/// wallpapers.
+///
+/// ```
+/// use relative_luminance::theme::{HysteresisThreshold, Theme};
+///
+/// let mut threshold = HysteresisThreshold::new(0.15f32, 0.21, Theme::Dark);
+///
+/// assert_eq!(threshold.update(0.2), Theme::Dark);
+/// assert_eq!(threshold.update(0.25), Theme::Light);
+/// assert_eq!(threshold.update(0.16), Theme::Light);
+/// assert_eq!(threshold.update(0.1), Theme::Dark);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HysteresisThreshold<F> {
+    lower: F,
+    upper: F,
+    theme: Theme,
+}
+
+impl<F: Float> HysteresisThreshold<F> {
+    /// Creates a new `HysteresisThreshold<F>` with relative luminance bounds, starting
+    /// with a theme.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lower` is greater than `upper`.
+    pub fn new(lower: F, upper: F, theme: Theme) -> Self {
+        assert!(
+            lower <= upper,
+            "lower bound must not be greater than upper bound"
+        );
+        HysteresisThreshold {
+            lower,
+            upper,
+            theme,
+        }
+    }
+
+    /// Creates a new `HysteresisThreshold<F>` with bounds `margin` below and above
+    /// [`MIDDLE_LUMINANCE`], starting with the theme of a luminance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `margin` is negative.
+    pub fn around_middle(margin: F, luminance: F) -> Self {
+        let middle = F::from_f64(MIDDLE_LUMINANCE);
+        Self::new(
+            middle - margin,
+            middle + margin,
+            Theme::from_luminance(luminance),
+        )
+    }
+
+    /// Gets the current theme.
+    pub fn theme(&self) -> Theme {
+        self.theme
+    }
+
+    /// Updates the theme with a new relative luminance, and gets it.
+    ///
+    /// A dark theme becomes light above the upper bound, and a light theme becomes
+    /// dark below the lower bound.
+    pub fn update(&mut self, luminance: F) -> Theme {
+        self.theme = match self.theme {
+            Theme::Dark if luminance > self.upper => Theme::Light,
+            Theme::Light if luminance < self.lower => Theme::Dark,
+            theme => theme,
+        };
+        self.theme
+    }
+
+    /// Updates the theme with the
+    /// [WCAG relative luminance](Luminance::wcag_relative_luminance) of a color, and
+    /// gets it.
+    pub fn update_color<T, C>(&mut self, color: &C) -> Theme
+    where
+        T: LuminanceValue<Weighted = F>,
+        T::Channel: Float,
+        C: Luminance<T> + ?Sized,
+    {
+        self.update(color.wcag_relative_luminance())
+    }
+}
+
+/// An error from parsing the reply to an OSC 11 query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseOscError {
+    /// The color doesn't start with `rgb:` or `rgba:`.
+    MissingPrefix,
+    /// The color doesn't have 3 components, or 4 with `rgba:`.
+    InvalidLength,
+    /// A component doesn't have 1 to 4 hex digits.
+    InvalidComponent,
+}
+
+impl fmt::Display for ParseOscError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseOscError::MissingPrefix => write!(f, "color doesn't start with 'rgb:'"),
+            ParseOscError::InvalidLength => write!(f, "color has the wrong number of components"),
+            ParseOscError::InvalidComponent => {
+                write!(f, "color component must have 1 to 4 hex digits")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseOscError {}
+
+/// The background color of a terminal, from [`parse_osc11`].
+#[derive(Clone, Copy, Debug)]
+pub struct TerminalBackground {
+    /// The background color.
+    pub rgb: Rgb<f32>,
+    /// If the background is light or dark.
+    pub theme: Theme,
+}
+
+/// Parses the reply of a terminal to the OSC 11 query (`ESC ] 11 ; ? BEL`), which
+/// reports its background color like `rgb:RRRR/GGGG/BBBB`.
+///
+/// The reply can include the `ESC ] 11 ;` prefix and the `BEL` or `ESC \` terminator.
+/// Each component can have 1 to 4 hex digits, and is scaled to [0.0, 1.0] by its
+/// number of digits, like in X11 color names. The alpha component of `rgba:` replies
+/// is ignored.
+///
+/// ```
+/// use relative_luminance::theme::{self, Theme};
+///
+/// let background = theme::parse_osc11("\x1b]11;rgb:ffff/ffff/dddd\x1b\\").unwrap();
+///
+/// assert_eq!((background.rgb.r, background.rgb.b), (1.0, 0.8666667));
+/// assert_eq!(background.theme, Theme::Light);
+/// assert_eq!(theme::parse_osc11("rgb:1e/1e/2e").unwrap().theme, Theme::Dark);
+/// ```
+pub fn parse_osc11(reply: &str) -> Result<TerminalBackground, ParseOscError> {
+    let reply = reply.strip_prefix("\x1b]11;").unwrap_or(reply);
+    let reply = reply
+        .strip_suffix('\x07')
+        .or_else(|| reply.strip_suffix("\x1b\\"))
+        .unwrap_or(reply);
+    let (components, len) = if let Some(components) = reply.strip_prefix("rgba:") {
+        (components, 4)
+    } else if let Some(components) = reply.strip_prefix("rgb:") {
+        (components, 3)
+    } else {
+        return Err(ParseOscError::MissingPrefix);
+    };
+
+    let mut channels = [0.0; 4];
+    let mut count = 0;
+    for component in components.split('/') {
+        let channel = channels
+            .get_mut(count)
+            .ok_or(ParseOscError::InvalidLength)?;
+        *channel = parse_component(component)?;
+        count += 1;
+    }
+    if count != len {
+        return Err(ParseOscError::InvalidLength);
+    }
+    let rgb = Rgb::new(channels[0], channels[1], channels[2]);
+    Ok(TerminalBackground {
+        rgb,
+        theme: Theme::from_luminance(rgb.wcag_relative_luminance()),
+    })
+}
+
+/// Parses a component with 1 to 4 hex digits to [0.0, 1.0].
+fn parse_component(component: &str) -> Result<f32, ParseOscError> {
+    if !(1..=4).contains(&component.len()) || !component.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ParseOscError::InvalidComponent);
+    }
+    let value = u16::from_str_radix(component, 16).map_err(|_| ParseOscError::InvalidComponent)?;
+    let max = (1u32 << (4 * component.len())) - 1;
+    Ok(f32::from(value) / max as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rgb;
+
+    #[test]
+    fn test_middle_luminance() {
+        let middle = (1.05f64 * 0.05).sqrt() - 0.05;
+        assert!((middle - MIDDLE_LUMINANCE).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_hysteresis_around_middle() {
+        let mut threshold = HysteresisThreshold::around_middle(0.05f64, 0.0);
+        assert_eq!(threshold.theme(), Theme::Dark);
+        let gray = Rgb::<f64>::new(0.47, 0.47, 0.47);
+        assert_eq!(threshold.update_color(&gray), Theme::Dark);
+        assert_eq!(threshold.update(0.3), Theme::Light);
+        assert_eq!(threshold.update_color(&gray), Theme::Light);
+    }
+
+    #[test]
+    #[should_panic(expected = "lower bound")]
+    fn test_hysteresis_bounds_out_of_order() {
+        HysteresisThreshold::new(0.3f32, 0.2, Theme::Light);
+    }
+
+    #[test]
+    fn test_parse_osc11() {
+        let background = parse_osc11("\x1b]11;rgb:8/80/800\x07").unwrap();
+        let Rgb { r, g, b } = background.rgb;
+        assert_eq!((r, g, b), (8.0 / 15.0, 128.0 / 255.0, 2048.0 / 4095.0));
+        assert!(parse_osc11("rgba:0000/0000/0000/ffff").is_ok());
+        assert_eq!(
+            parse_osc11("#000000").unwrap_err(),
+            ParseOscError::MissingPrefix
+        );
+        assert_eq!(
+            parse_osc11("rgb:00/00").unwrap_err(),
+            ParseOscError::InvalidLength
+        );
+        assert_eq!(
+            parse_osc11("rgb:00/00/00/00").unwrap_err(),
+            ParseOscError::InvalidLength
+        );
+        assert_eq!(
+            parse_osc11("rgb:00/00/+0").unwrap_err(),
+            ParseOscError::InvalidComponent
+        );
+    }
+
+    #[test]
+    fn test_surfaces_outweighed_by_background() {
+        let white = Rgb::<f64>::new(1.0, 1.0, 1.0);
+        let dark = Rgb::<f64>::new(0.1, 0.1, 0.1);
+        assert_eq!(detect_theme(&white, &[dark, dark, dark]), Theme::Light);
+        assert_eq!(detect_theme(&dark, &[] as &[Rgb<f64>]), Theme::Dark);
+    }
+}
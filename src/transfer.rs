@@ -0,0 +1,347 @@
+//! Transfer functions for converting between encoded channels and linear light.
+//!
+//! Relative luminance is a weighted sum of *linear* channels, but most colors are
+//! stored with a transfer function (like a gamma curve) applied. A
+//! [`TransferFunction`] can decode the channels before they are weighted with
+//! [`Luminance::decoded_relative_luminance`].
+//!
+//! ```
+//! use relative_luminance::transfer::{Gamma, Linear, Srgb};
+//! use relative_luminance::{Luminance, Rgb};
+//!
+//! let gray: Rgb<f32> = Rgb::new(0.5, 0.5, 0.5);
+//!
+//! assert_eq!(gray.decoded_relative_luminance(&Linear), gray.relative_luminance());
+//! assert!((gray.decoded_relative_luminance(&Srgb) - 0.214).abs() < 0.001);
+//! assert!((gray.decoded_relative_luminance(&Gamma(2.2)) - 0.218).abs() < 0.001);
+//! ```
+//!
+//! [`Luminance::decoded_relative_luminance`]: crate::Luminance::decoded_relative_luminance
+use crate::standard::{Bt2020, ProPhotoRgb};
+use crate::{Float, Luminance, LuminanceValue, Standard};
+
+/// Converts between encoded channel values and linear light.
+///
+/// ```
+/// use relative_luminance::transfer::TransferFunction;
+/// use relative_luminance::Float;
+///
+/// /// A gamma of 2.0, which is cheap to calculate.
+/// struct Square;
+///
+/// impl TransferFunction for Square {
+///     fn decode<F: Float>(&self, encoded: F) -> F {
+///         encoded * encoded
+///     }
+///
+///     fn encode<F: Float>(&self, linear: F) -> F {
+///         linear.powf(F::from_f64(0.5))
+///     }
+/// }
+///
+/// assert_eq!(Square.decode(0.5f32), 0.25);
+/// assert_eq!(Square.encode(0.25f32), 0.5);
+/// ```
+pub trait TransferFunction {
+    /// Decodes an encoded channel value to linear light.
+    fn decode<F: Float>(&self, encoded: F) -> F;
+    /// Encodes a linear channel value.
+    fn encode<F: Float>(&self, linear: F) -> F;
+}
+
+/// Channels that are already linear.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Linear;
+
+impl TransferFunction for Linear {
+    #[inline]
+    fn decode<F: Float>(&self, encoded: F) -> F {
+        encoded
+    }
+
+    #[inline]
+    fn encode<F: Float>(&self, linear: F) -> F {
+        linear
+    }
+}
+
+/// A pure power curve, like the 2.2 gamma that many displays approximate.
+///
+/// ```
+/// # use relative_luminance::transfer::{Gamma, TransferFunction};
+/// assert_eq!(Gamma(2.0).decode(0.5f64), 0.25);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gamma(pub f64);
+
+impl TransferFunction for Gamma {
+    fn decode<F: Float>(&self, encoded: F) -> F {
+        encoded.powf(F::from_f64(self.0))
+    }
+
+    fn encode<F: Float>(&self, linear: F) -> F {
+        linear.powf(F::from_f64(1.0 / self.0))
+    }
+}
+
+/// The piecewise [sRGB][srgb] transfer function.
+///
+/// This uses the 0.04045 threshold from the sRGB specification, which is also the
+/// value used by WCAG 2.2.
+///
+/// [srgb]: https://en.wikipedia.org/wiki/SRGB#Transfer_function_(%22gamma%22)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Srgb;
+
+impl TransferFunction for Srgb {
+    fn decode<F: Float>(&self, encoded: F) -> F {
+        if encoded <= F::from_f64(0.04045) {
+            encoded / F::from_f64(12.92)
+        } else {
+            ((encoded + F::from_f64(0.055)) / F::from_f64(1.055)).powf(F::from_f64(2.4))
+        }
+    }
+
+    fn encode<F: Float>(&self, linear: F) -> F {
+        if linear <= F::from_f64(0.0031308) {
+            linear * F::from_f64(12.92)
+        } else {
+            F::from_f64(1.055) * linear.powf(F::from_f64(1.0 / 2.4)) - F::from_f64(0.055)
+        }
+    }
+}
+
+/// The [SMPTE ST 2084][pq] perceptual quantizer (PQ) used by HDR10 and Dolby Vision.
+///
+/// Linear values are relative to the PQ reference peak of 10,000 cd/m², so the
+/// relative luminance of a PQ-encoded color is also relative to that peak. Use
+/// [`Pq::absolute_luminance`] to get the luminance in cd/m² instead.
+///
+/// [pq]: https://en.wikipedia.org/wiki/Perceptual_quantizer
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Pq;
+
+impl Pq {
+    const M1: f64 = 2610.0 / 16384.0;
+    const M2: f64 = 2523.0 / 4096.0 * 128.0;
+    const C1: f64 = 3424.0 / 4096.0;
+    const C2: f64 = 2413.0 / 4096.0 * 32.0;
+    const C3: f64 = 2392.0 / 4096.0 * 32.0;
+
+    /// The luminance, in cd/m², of a linear value of 1.0.
+    pub const PEAK_NITS: f64 = 10_000.0;
+
+    /// Gets the absolute luminance, in cd/m², of a PQ-encoded color with the BT.2020
+    /// primaries used by HDR10.
+    ///
+    /// ```
+    /// use relative_luminance::transfer::{Pq, TransferFunction};
+    /// use relative_luminance::Rgb;
+    ///
+    /// // The SDR reference white of 100 cd/m²
+    /// let signal = Pq.encode(0.01);
+    /// let white: Rgb<f64> = Rgb::new(signal, signal, signal);
+    ///
+    /// assert!((Pq::absolute_luminance(&white) - 100.0).abs() < 1e-6);
+    /// ```
+    pub fn absolute_luminance<T, C>(color: &C) -> T::Weighted
+    where
+        T: LuminanceValue,
+        T::Channel: Float,
+        T::Weighted: Float,
+        Bt2020: Standard<T>,
+        C: Luminance<T> + ?Sized,
+    {
+        let relative = color
+            .luminance_rgb()
+            .decode(&Pq)
+            .relative_luminance_as::<Bt2020>();
+        relative * <T::Weighted as Float>::from_f64(Self::PEAK_NITS)
+    }
+}
+
+impl TransferFunction for Pq {
+    fn decode<F: Float>(&self, encoded: F) -> F {
+        let f = F::from_f64;
+        let power = encoded.powf(f(1.0 / Self::M2));
+        let numerator = power - f(Self::C1);
+        if numerator <= f(0.0) {
+            return f(0.0);
+        }
+        (numerator / (f(Self::C2) - f(Self::C3) * power)).powf(f(1.0 / Self::M1))
+    }
+
+    fn encode<F: Float>(&self, linear: F) -> F {
+        let f = F::from_f64;
+        let power = linear.powf(f(Self::M1));
+        ((f(Self::C1) + f(Self::C2) * power) / (f(1.0) + f(Self::C3) * power)).powf(f(Self::M2))
+    }
+}
+
+/// The [ITU-R BT.2100][bt2100] hybrid log-gamma (HLG) curve used by broadcast HDR.
+///
+/// This converts between the encoded signal and normalized *scene* light in
+/// [0.0, 1.0], without the system gamma that a display applies. Use
+/// [`Hlg::display_luminance`] to include the system gamma of a display with a given
+/// nominal peak luminance.
+///
+/// [bt2100]: https://www.itu.int/rec/R-REC-BT.2100
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Hlg;
+
+impl Hlg {
+    const A: f64 = 0.17883277;
+    const B: f64 = 1.0 - 4.0 * Self::A;
+    const C: f64 = 0.5599107295;
+
+    /// The nominal peak luminance, in cd/m², of the BT.2100 reference display.
+    pub const REFERENCE_PEAK_NITS: f64 = 1000.0;
+
+    /// Gets the system gamma for a display with a nominal peak luminance in cd/m².
+    ///
+    /// ```
+    /// # use relative_luminance::transfer::Hlg;
+    /// assert_eq!(Hlg::system_gamma(Hlg::REFERENCE_PEAK_NITS), 1.2);
+    /// ```
+    pub fn system_gamma(peak_nits: f64) -> f64 {
+        1.2 + 0.42 * (peak_nits / Self::REFERENCE_PEAK_NITS).log10()
+    }
+
+    /// Gets the displayed luminance of an HLG-encoded color with BT.2020 primaries,
+    /// relative to the nominal peak luminance of the display.
+    ///
+    /// This applies the HLG OOTF to the scene luminance, so unlike decoding the
+    /// channels with [`Hlg`], the result depends on the display.
+    ///
+    /// ```
+    /// use relative_luminance::transfer::{Hlg, TransferFunction};
+    /// use relative_luminance::Rgb;
+    ///
+    /// let signal = Hlg.encode(0.25);
+    /// let gray: Rgb<f64> = Rgb::new(signal, signal, signal);
+    ///
+    /// let dim = Hlg::display_relative_luminance(&gray, 500.0);
+    /// let bright = Hlg::display_relative_luminance(&gray, 2000.0);
+    ///
+    /// assert!((Hlg::display_relative_luminance(&gray, 1000.0) - 0.25f64.powf(1.2)).abs() < 1e-9);
+    /// assert!(bright < dim);
+    /// ```
+    pub fn display_relative_luminance<T, C>(color: &C, peak_nits: f64) -> T::Weighted
+    where
+        T: LuminanceValue,
+        T::Channel: Float,
+        T::Weighted: Float,
+        Bt2020: Standard<T>,
+        C: Luminance<T> + ?Sized,
+    {
+        let scene = color
+            .luminance_rgb()
+            .decode(&Hlg)
+            .relative_luminance_as::<Bt2020>();
+        scene.powf(<T::Weighted as Float>::from_f64(Self::system_gamma(
+            peak_nits,
+        )))
+    }
+
+    /// Gets the displayed luminance, in cd/m², of an HLG-encoded color with BT.2020
+    /// primaries on a display with a nominal peak luminance in cd/m².
+    ///
+    /// ```
+    /// use relative_luminance::transfer::Hlg;
+    /// use relative_luminance::Rgb;
+    ///
+    /// let white: Rgb<f64> = Rgb::new(1.0, 1.0, 1.0);
+    ///
+    /// assert!((Hlg::display_luminance(&white, 1000.0) - 1000.0).abs() < 0.01);
+    /// assert!((Hlg::display_luminance(&white, 400.0) - 400.0).abs() < 0.01);
+    /// ```
+    pub fn display_luminance<T, C>(color: &C, peak_nits: f64) -> T::Weighted
+    where
+        T: LuminanceValue,
+        T::Channel: Float,
+        T::Weighted: Float,
+        Bt2020: Standard<T>,
+        C: Luminance<T> + ?Sized,
+    {
+        Self::display_relative_luminance(color, peak_nits)
+            * <T::Weighted as Float>::from_f64(peak_nits)
+    }
+}
+
+impl TransferFunction for Hlg {
+    fn decode<F: Float>(&self, encoded: F) -> F {
+        let f = F::from_f64;
+        if encoded <= f(0.5) {
+            encoded * encoded / f(3.0)
+        } else {
+            (((encoded - f(Self::C)) / f(Self::A)).exp() + f(Self::B)) / f(12.0)
+        }
+    }
+
+    fn encode<F: Float>(&self, linear: F) -> F {
+        let f = F::from_f64;
+        if linear <= f(1.0 / 12.0) {
+            (f(3.0) * linear).powf(f(0.5))
+        } else {
+            f(Self::A) * (f(12.0) * linear - f(Self::B)).ln() + f(Self::C)
+        }
+    }
+}
+
+impl TransferFunction for ProPhotoRgb {
+    /// Same as [`ProPhotoRgb::to_linear`].
+    fn decode<F: Float>(&self, encoded: F) -> F {
+        ProPhotoRgb::to_linear(encoded)
+    }
+
+    fn encode<F: Float>(&self, linear: F) -> F {
+        if linear < F::from_f64(1.0 / 512.0) {
+            linear * F::from_f64(16.0)
+        } else {
+            linear.powf(F::from_f64(1.0 / 1.8))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_round_trips<Tf: TransferFunction>(transfer: Tf) {
+        for i in 0..=20 {
+            let encoded = f64::from(i) / 20.0;
+            let round_trip = transfer.encode(transfer.decode(encoded));
+            assert!(
+                (round_trip - encoded).abs() < 1e-6,
+                "{encoded} != {round_trip}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_trips() {
+        assert_round_trips(Linear);
+        assert_round_trips(Gamma(2.2));
+        assert_round_trips(Srgb);
+        assert_round_trips(Pq);
+        assert_round_trips(Hlg);
+        assert_round_trips(ProPhotoRgb);
+    }
+
+    #[test]
+    fn test_pq_reference_values() {
+        assert_eq!(Pq.decode(0.0f64), 0.0);
+        assert!((Pq.decode(1.0f64) - 1.0).abs() < 1e-12);
+        // 100 cd/m², the SDR reference white, is encoded at about 0.508.
+        assert!((Pq.encode(0.01f64) - 0.508).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_hlg_is_continuous() {
+        let below = Hlg.decode(0.5f64 - 1e-12);
+        let above = Hlg.decode(0.5f64 + 1e-12);
+        assert!((below - 1.0 / 12.0).abs() < 1e-9);
+        assert!((above - 1.0 / 12.0).abs() < 1e-9);
+        assert!((Hlg.decode(1.0f64) - 1.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,280 @@
+//! Channels of video pipelines, stored as 8-bit to 16-bit integers.
+//!
+//! Video is often [limited range][range] ("TV" or "studio swing"), where black and
+//! white are 64 and 940 in 10-bit video, instead of 0 and 1023.
+//!
+//! ```
+//! use relative_luminance::video::{Range, VideoRgb};
+//! use relative_luminance::Luminance;
+//!
+//! let full = VideoRgb::new(1023, 1023, 1023, 10, Range::Full);
+//! let limited = VideoRgb::new(940, 940, 940, 10, Range::Limited);
+//!
+//! assert_eq!(full.relative_luminance(), 1.0);
+//! assert_eq!(limited.relative_luminance(), 1.0);
+//! ```
+//!
+//! [range]: https://www.itu.int/rec/R-REC-BT.2100
+use crate::standard::{Bt2020, Bt601, Bt709};
+use crate::{Luminance, Rgb, Weights};
+
+/// The range of code values used for black to white.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Range {
+    /// Every code value is used, like 0 to 1023 in 10-bit video.
+    #[default]
+    Full,
+    /// The "narrow" range of broadcast video, like 64 to 940 in 10-bit video.
+    Limited,
+}
+
+/// Normalizes a channel with the given bit depth and range to [0.0, 1.0].
+///
+/// Limited-range values below black or above white aren't clamped, so they stay
+/// outside of [0.0, 1.0].
+///
+/// # Panics
+///
+/// May panic if `bit_depth` isn't in the range [8, 16].
+///
+/// ```
+/// use relative_luminance::video::{self, Range};
+///
+/// assert_eq!(video::normalize(64, 10, Range::Limited), 0.0);
+/// assert_eq!(video::normalize(4095, 12, Range::Full), 1.0);
+/// assert_eq!(video::normalize(3760, 12, Range::Limited), 1.0);
+/// ```
+pub fn normalize(value: u16, bit_depth: u32, range: Range) -> f32 {
+    let (black, white) = match range {
+        Range::Full => (0.0, ((1u32 << bit_depth) - 1) as f32),
+        Range::Limited => {
+            let scale = (1u32 << (bit_depth - 8)) as f32;
+            (16.0 * scale, 235.0 * scale)
+        }
+    };
+    (f32::from(value) - black) / (white - black)
+}
+
+/// RGB channels with a bit depth from 8 to 16 bits, stored in `u16`s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VideoRgb {
+    /// The red channel.
+    pub r: u16,
+    /// The green channel.
+    pub g: u16,
+    /// The blue channel.
+    pub b: u16,
+    bit_depth: u32,
+    /// The range of the channels.
+    pub range: Range,
+}
+
+impl VideoRgb {
+    /// Creates a new `VideoRgb`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_depth` isn't in the range [8, 16].
+    pub fn new(r: u16, g: u16, b: u16, bit_depth: u32, range: Range) -> Self {
+        assert!(
+            (8..=16).contains(&bit_depth),
+            "bit depth must be from 8 to 16"
+        );
+        VideoRgb {
+            r,
+            g,
+            b,
+            bit_depth,
+            range,
+        }
+    }
+
+    /// Gets the number of bits used by each channel, like 10 or 12.
+    pub fn bit_depth(&self) -> u32 {
+        self.bit_depth
+    }
+}
+
+impl Luminance<f32> for VideoRgb {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        let normalize = |value| normalize(value, self.bit_depth, self.range);
+        Rgb::new(normalize(self.r), normalize(self.g), normalize(self.b))
+    }
+}
+
+/// Normalizes a chroma (Cb or Cr) channel with the given bit depth and range to
+/// [-0.5, 0.5].
+///
+/// # Panics
+///
+/// May panic if `bit_depth` isn't in the range [8, 16].
+///
+/// ```
+/// use relative_luminance::video::{self, Range};
+///
+/// assert_eq!(video::normalize_chroma(512, 10, Range::Limited), 0.0);
+/// assert_eq!(video::normalize_chroma(960, 10, Range::Limited), 0.5);
+/// assert_eq!(video::normalize_chroma(128, 8, Range::Full), 0.0);
+/// ```
+pub fn normalize_chroma(value: u16, bit_depth: u32, range: Range) -> f32 {
+    let center = (1u32 << (bit_depth - 1)) as f32;
+    let scale = match range {
+        Range::Full => ((1u32 << bit_depth) - 1) as f32,
+        Range::Limited => (224u32 << (bit_depth - 8)) as f32,
+    };
+    (f32::from(value) - center) / scale
+}
+
+/// The matrix coefficients used to encode Y'CbCr.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Matrix {
+    /// The [`Bt601`] coefficients of standard-definition video.
+    Bt601,
+    /// The [`Bt709`] coefficients of high-definition video.
+    #[default]
+    Bt709,
+    /// The [`Bt2020`] non-constant luminance coefficients of UHD video.
+    Bt2020,
+}
+
+impl Matrix {
+    /// Gets the luma weights of the matrix.
+    pub fn weights(&self) -> Weights<f32> {
+        match self {
+            Matrix::Bt601 => Weights::from_standard::<Bt601>(),
+            Matrix::Bt709 => Weights::from_standard::<Bt709>(),
+            Matrix::Bt2020 => Weights::from_standard::<Bt2020>(),
+        }
+    }
+}
+
+/// Y'CbCr channels with a bit depth from 8 to 16 bits, stored in `u16`s.
+///
+/// The [`Luminance`] channels are the gamma-encoded R'G'B' channels, and
+/// [`Luminance::relative_luminance`] is the normalized luma (Y'), which is the R'G'B'
+/// channels weighted with the matrix coefficients. This is consistent with
+/// [`Rgb::relative_luminance_as`](crate::Luminance::relative_luminance_as) on the
+/// same R'G'B' channels.
+///
+/// ```
+/// use relative_luminance::video::{Matrix, Range, YCbCr};
+/// use relative_luminance::Luminance;
+///
+/// // 8-bit limited-range BT.709 red
+/// let red = YCbCr::new(63, 102, 240, 8, Range::Limited, Matrix::Bt709);
+/// let rgb = red.luminance_rgb();
+///
+/// assert!((rgb.r - 1.0).abs() < 0.01);
+/// assert!(rgb.g.abs() < 0.01 && rgb.b.abs() < 0.01);
+/// assert!((red.relative_luminance() - 0.2126).abs() < 0.01);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct YCbCr {
+    /// The luma channel.
+    pub y: u16,
+    /// The blue-difference chroma channel.
+    pub cb: u16,
+    /// The red-difference chroma channel.
+    pub cr: u16,
+    bit_depth: u32,
+    /// The range of the channels.
+    pub range: Range,
+    /// The matrix coefficients that were used to encode the channels.
+    pub matrix: Matrix,
+}
+
+impl YCbCr {
+    /// Creates a new `YCbCr`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_depth` isn't in the range [8, 16].
+    pub fn new(y: u16, cb: u16, cr: u16, bit_depth: u32, range: Range, matrix: Matrix) -> Self {
+        assert!(
+            (8..=16).contains(&bit_depth),
+            "bit depth must be from 8 to 16"
+        );
+        YCbCr {
+            y,
+            cb,
+            cr,
+            bit_depth,
+            range,
+            matrix,
+        }
+    }
+
+    /// Gets the number of bits used by each channel, like 10 or 12.
+    pub fn bit_depth(&self) -> u32 {
+        self.bit_depth
+    }
+}
+
+impl Luminance<f32> for YCbCr {
+    fn luminance_rgb(&self) -> Rgb<f32> {
+        let Weights {
+            r: kr,
+            g: kg,
+            b: kb,
+        } = self.matrix.weights();
+        let y = normalize(self.y, self.bit_depth, self.range);
+        let cb = normalize_chroma(self.cb, self.bit_depth, self.range);
+        let cr = normalize_chroma(self.cr, self.bit_depth, self.range);
+        let r = y + 2.0 * (1.0 - kr) * cr;
+        let b = y + 2.0 * (1.0 - kb) * cb;
+        let g = (y - kr * r - kb * b) / kg;
+        Rgb::new(r, g, b)
+    }
+
+    /// Gets the normalized luma.
+    fn relative_luminance(&self) -> f32 {
+        normalize(self.y, self.bit_depth, self.range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limited_range_bounds() {
+        for bit_depth in [8, 10, 12, 16] {
+            let shift = bit_depth - 8;
+            assert_eq!(normalize(16 << shift, bit_depth, Range::Limited), 0.0);
+            assert_eq!(normalize(235 << shift, bit_depth, Range::Limited), 1.0);
+        }
+        assert_eq!(normalize(u16::MAX, 16, Range::Full), 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit depth must be from 8 to 16")]
+    fn test_video_rgb_rejects_bit_depth() {
+        VideoRgb::new(0, 0, 0, 7, Range::Full);
+    }
+
+    #[test]
+    fn test_video_rgb_bit_depth() {
+        let white = VideoRgb::new(940, 940, 940, 10, Range::Limited);
+        assert_eq!(white.bit_depth(), 10);
+        assert_eq!(white.relative_luminance(), 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit depth must be from 8 to 16")]
+    fn test_ycbcr_rejects_bit_depth() {
+        YCbCr::new(0, 0, 0, 17, Range::Full, Matrix::Bt709);
+    }
+
+    #[test]
+    fn test_ycbcr_matches_rgb_luma() {
+        // 10-bit full-range colors with some chroma
+        for matrix in [Matrix::Bt601, Matrix::Bt709, Matrix::Bt2020] {
+            let color = YCbCr::new(600, 400, 700, 10, Range::Full, matrix);
+            let weighted = color
+                .luminance_rgb()
+                .relative_luminance_with(&matrix.weights());
+            assert!((weighted - color.relative_luminance()).abs() < 1e-6);
+            assert_eq!(color.bit_depth(), 10);
+        }
+    }
+}
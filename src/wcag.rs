@@ -0,0 +1,225 @@
+//! Checks of text against the WCAG 2.x [contrast (minimum)][minimum] and
+//! [contrast (enhanced)][enhanced] criteria.
+//!
+//! The minimum contrast ratio depends on the size of the text, so this takes the font
+//! size and weight into account, unlike the checks of [`ContrastRatio`].
+//!
+//! ```
+//! use relative_luminance::wcag::{self, TextSize};
+//! use relative_luminance::Rgb;
+//!
+//! let gray: Rgb<f32> = Rgb::new(0.5, 0.5, 0.5);
+//! let white: Rgb<f32> = Rgb::new(1.0, 1.0, 1.0);
+//!
+//! let body = wcag::evaluate(&gray, &white, 16.0, false);
+//! let heading = wcag::evaluate(&gray, &white, 24.0, false);
+//!
+//! assert_eq!(body.text_size, TextSize::Normal);
+//! assert!(!body.aa);
+//! assert_eq!(heading.text_size, TextSize::Large);
+//! assert!(heading.aa && !heading.aaa);
+//! ```
+//!
+//! [minimum]: https://www.w3.org/TR/WCAG22/#contrast-minimum
+//! [enhanced]: https://www.w3.org/TR/WCAG22/#contrast-enhanced
+use crate::contrast::ContrastRatio;
+use crate::{Float, Luminance, LuminanceValue};
+
+/// The smallest size of large text, 18pt, in CSS pixels.
+const LARGE_PX: f32 = 24.0;
+/// The smallest size of large bold text, 14pt, in CSS pixels.
+const LARGE_BOLD_PX: f32 = 14.0 * 4.0 / 3.0;
+
+/// The size of text, which decides the minimum contrast ratios.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum TextSize {
+    /// Text smaller than large text.
+    Normal,
+    /// Text that is at least 18pt, or at least 14pt and bold.
+    Large,
+}
+
+impl TextSize {
+    /// Classifies text with a font size in CSS pixels, where 1pt is 4/3px.
+    ///
+    /// ```
+    /// use relative_luminance::wcag::TextSize;
+    ///
+    /// assert_eq!(TextSize::classify(18.0, false), TextSize::Normal);
+    /// assert_eq!(TextSize::classify(19.0, true), TextSize::Large);
+    /// ```
+    pub fn classify(font_px: f32, bold: bool) -> Self {
+        let minimum = if bold { LARGE_BOLD_PX } else { LARGE_PX };
+        if font_px >= minimum {
+            TextSize::Large
+        } else {
+            TextSize::Normal
+        }
+    }
+}
+
+/// The result of [`evaluate`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Report<F> {
+    /// The contrast ratio of the text and background colors.
+    pub ratio: ContrastRatio<F>,
+    /// The size of the text.
+    pub text_size: TextSize,
+    /// If the text passes level AA.
+    pub aa: bool,
+    /// If the text passes level AAA.
+    pub aaa: bool,
+}
+
+/// Checks text with a font size in CSS pixels against levels AA and AAA.
+///
+/// See [`TextSize::classify`] for which text is large.
+pub fn evaluate<T, Fg, Bg>(fg: &Fg, bg: &Bg, font_px: f32, bold: bool) -> Report<T::Weighted>
+where
+    T: LuminanceValue,
+    T::Channel: Float,
+    T::Weighted: Float,
+    Fg: Luminance<T> + ?Sized,
+    Bg: Luminance<T> + ?Sized,
+{
+    report(
+        ContrastRatio::between(fg, bg),
+        TextSize::classify(font_px, bold),
+    )
+}
+
+/// Checks a contrast ratio for text of a size.
+fn report<F: Float>(ratio: ContrastRatio<F>, text_size: TextSize) -> Report<F> {
+    let (aa, aaa) = match text_size {
+        TextSize::Normal => (ratio.passes_aa(), ratio.passes_aaa()),
+        TextSize::Large => (ratio.passes_aa_large(), ratio.passes_aaa_large()),
+    };
+    Report {
+        ratio,
+        text_size,
+        aa,
+        aaa,
+    }
+}
+
+/// A foreground color and a background color that are used together, like the text
+/// and surface colors of design tokens.
+#[derive(Clone, Copy, Debug)]
+pub struct RolePair<'a, C: ?Sized> {
+    /// A name of the pair, like `"text-on-surface"`.
+    pub name: &'a str,
+    /// The foreground color.
+    pub foreground: &'a C,
+    /// The background color.
+    pub background: &'a C,
+    /// The size of text in the foreground color.
+    pub text_size: TextSize,
+}
+
+/// A pair that fails level AAA, and maybe level AA, from [`audit`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+pub struct Failure<'a, F> {
+    /// The name of the pair.
+    pub name: &'a str,
+    /// The report of the pair, where `aaa` is always `false`.
+    pub report: Report<F>,
+}
+
+/// Checks every pair of colors, and gets the pairs that fail level AAA, in order.
+///
+/// Pairs that also fail level AA have a [`Report::aa`] of `false`, so CI can be gated on
+/// either level.
+///
+/// ```
+/// use relative_luminance::wcag::{self, RolePair, TextSize};
+/// use relative_luminance::Rgb;
+///
+/// let surface: Rgb<f32> = Rgb::new(1.0, 1.0, 1.0);
+/// let text: Rgb<f32> = Rgb::new(0.1, 0.1, 0.1);
+/// let link: Rgb<f32> = Rgb::new(0.3, 0.5, 1.0);
+/// let pairs = [
+///     RolePair {
+///         name: "text-on-surface",
+///         foreground: &text,
+///         background: &surface,
+///         text_size: TextSize::Normal,
+///     },
+///     RolePair {
+///         name: "link-on-surface",
+///         foreground: &link,
+///         background: &surface,
+///         text_size: TextSize::Normal,
+///     },
+/// ];
+/// let failures = wcag::audit(&pairs);
+///
+/// assert_eq!(failures.len(), 1);
+/// assert_eq!(failures[0].name, "link-on-surface");
+/// assert!(failures.iter().any(|failure| !failure.report.aa));
+/// ```
+pub fn audit<'a, T, C>(pairs: &[RolePair<'a, C>]) -> Vec<Failure<'a, T::Weighted>>
+where
+    T: LuminanceValue,
+    T::Channel: Float,
+    T::Weighted: Float,
+    C: Luminance<T> + ?Sized,
+{
+    pairs
+        .iter()
+        .map(|pair| Failure {
+            name: pair.name,
+            report: report(
+                ContrastRatio::between(pair.foreground, pair.background),
+                pair.text_size,
+            ),
+        })
+        .filter(|failure| !failure.report.aaa)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rgb;
+
+    #[test]
+    fn test_large_text_boundaries() {
+        assert_eq!(TextSize::classify(23.9, false), TextSize::Normal);
+        assert_eq!(TextSize::classify(24.0, false), TextSize::Large);
+        assert_eq!(TextSize::classify(18.6, true), TextSize::Normal);
+        assert_eq!(TextSize::classify(18.7, true), TextSize::Large);
+    }
+
+    #[test]
+    fn test_audit_uses_text_size() {
+        let gray = Rgb::<f64>::new(0.5, 0.5, 0.5);
+        let white = Rgb::<f64>::new(1.0, 1.0, 1.0);
+        let pair = |name, text_size| RolePair {
+            name,
+            foreground: &gray,
+            background: &white,
+            text_size,
+        };
+        let failures = audit(&[
+            pair("body", TextSize::Normal),
+            pair("title", TextSize::Large),
+        ]);
+        let levels: Vec<_> = failures
+            .iter()
+            .map(|failure| (failure.name, failure.report.aa))
+            .collect();
+        assert_eq!(levels, [("body", false), ("title", true)]);
+    }
+
+    #[test]
+    fn test_black_on_white_passes_everything() {
+        let black = Rgb::<f64>::new(0.0, 0.0, 0.0);
+        let white = Rgb::<f64>::new(1.0, 1.0, 1.0);
+        let report = evaluate(&black, &white, 12.0, false);
+        assert_eq!(report.ratio, ContrastRatio(21.0));
+        assert!(report.aa && report.aaa);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{LuminanceValue, Standard};
+
+/// Luminance weights that can be chosen at runtime.
+///
+/// This is the runtime equivalent of a [`Standard`], and can be used with
+/// [`Luminance::relative_luminance_with`](crate::Luminance::relative_luminance_with).
+///
+/// ```
+/// use relative_luminance::standard::Bt601;
+/// use relative_luminance::{Luminance, Rgb, Weights};
+///
+/// let use_sd_video = true;
+/// let weights: Weights<f32> = if use_sd_video {
+///     Weights::from_standard::<Bt601>()
+/// } else {
+///     Weights { r: 0.2126, g: 0.7152, b: 0.0722 }
+/// };
+///
+/// let red: Rgb<f32> = Rgb::new(1.0, 0.0, 0.0);
+///
+/// assert_eq!(red.relative_luminance_with(&weights), 0.299);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(bound(
+        serialize = "T::Weight: ::serde::Serialize",
+        deserialize = "T::Weight: ::serde::Deserialize<'de>"
+    ))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize)
+)]
+pub struct Weights<T: LuminanceValue> {
+    pub r: T::Weight,
+    pub g: T::Weight,
+    pub b: T::Weight,
+}
+
+impl<T: LuminanceValue> Weights<T> {
+    /// Creates new `Weights<T>`.
+    pub fn new(r: T::Weight, g: T::Weight, b: T::Weight) -> Self {
+        Weights { r, g, b }
+    }
+
+    /// Gets the weights of a [`Standard`].
+    ///
+    /// ```
+    /// # use relative_luminance::standard::Bt2020;
+    /// # use relative_luminance::Weights;
+    /// let weights = Weights::<f64>::from_standard::<Bt2020>();
+    ///
+    /// assert_eq!(weights.g, 0.678);
+    /// ```
+    pub fn from_standard<S: Standard<T> + ?Sized>() -> Self {
+        Weights {
+            r: S::RED_WEIGHT,
+            g: S::GREEN_WEIGHT,
+            b: S::BLUE_WEIGHT,
+        }
+    }
+}
+
+impl<T: LuminanceValue> Default for Weights<T> {
+    /// The weights of `T`.
+    fn default() -> Self {
+        Weights {
+            r: T::RED_WEIGHT,
+            g: T::GREEN_WEIGHT,
+            b: T::BLUE_WEIGHT,
+        }
+    }
+}